@@ -3,12 +3,20 @@ import test from "ava";
 import { fileURLToPath } from "url";
 import {
   MediaPlayer,
+  MediaPlayerBackend,
+  MediaPlayerBusType,
+  MediaPlayerConnectionState,
   MediaPlayerMediaType,
   MediaPlayerPlaybackStatus,
+  MediaPlayerPlaylistOrdering,
+  MediaPlayerRepeatMode,
   MediaPlayerThumbnail,
   MediaPlayerThumbnailType,
+  XosmsErrorCode,
 } from "../index.js";
 
+const linuxTest = process.platform === "linux" ? test.serial : test.serial.skip;
+
 test.serial("can create mediaplayer", (t) => {
   t.notThrows(() => new MediaPlayer("xosms", "Xosms Test"));
 });
@@ -37,6 +45,104 @@ test.serial("can deactivate while already deactive", (t) => {
   t.notThrows(() => t.context.player.deactivate());
 });
 
+test.serial("can activate several media players at once", (t) => {
+  const first = new MediaPlayer("xosmstestfirst", "First");
+  const second = new MediaPlayer("xosmstestsecond", "Second");
+  t.notThrows(() => {
+    first.activate();
+    second.activate();
+    first.deactivate();
+    second.deactivate();
+  });
+});
+
+test.serial("connection state follows activation", (t) => {
+  const player = new MediaPlayer("xosmstestconnection", "Connection");
+  t.is(player.connectionState, MediaPlayerConnectionState.Disconnected);
+  player.activate();
+  t.is(player.connectionState, MediaPlayerConnectionState.Connected);
+  player.deactivate();
+  t.is(player.connectionState, MediaPlayerConnectionState.Disconnected);
+});
+
+linuxTest("activate returns the acquired service name", (t) => {
+  const first = new MediaPlayer("xosmstestowner", "First");
+  const second = new MediaPlayer("xosmstestowner", "Second");
+  t.is(first.activate(), "org.mpris.MediaPlayer2.xosmstestowner");
+  t.throws(() => second.activate());
+  t.is(
+    second.activate({ instanceFallback: true }),
+    `org.mpris.MediaPlayer2.xosmstestowner.instance${process.pid}`
+  );
+  first.deactivate();
+  second.deactivate();
+});
+
+test.serial("can activate and deactivate asynchronously", async (t) => {
+  const player = new MediaPlayer("xosmstestasync", "Async");
+  const activation = await player.activateAsync();
+  t.is(typeof activation.serviceName, "string");
+  t.false(activation.queued);
+  t.is(player.connectionState, MediaPlayerConnectionState.Connected);
+  await t.notThrowsAsync(() => player.deactivateAsync());
+  t.is(player.connectionState, MediaPlayerConnectionState.Disconnected);
+});
+
+linuxTest("activateAsync resolves with the acquired service name", async (t) => {
+  const player = new MediaPlayer("xosmstestasyncname", "Async");
+  t.deepEqual(await player.activateAsync({ timeout: 2 }), {
+    serviceName: "org.mpris.MediaPlayer2.xosmstestasyncname",
+    queued: false,
+  });
+  player.deactivate();
+});
+
+linuxTest("can not activate with an invalid timeout", (t) => {
+  const player = new MediaPlayer("xosmstesttimeout", "Timeout");
+  t.throws(() => player.activate({ timeout: 0 }));
+  t.throws(() => player.activateAsync({ timeout: -1 }));
+  t.throws(() => player.deactivateAsync(Number.NaN));
+});
+
+linuxTest("can not create mediaplayer with invalid service name", (t) => {
+  t.throws(() => new MediaPlayer("1xosms", "Xosms Test"));
+  t.throws(() => new MediaPlayer("xosms..test", "Xosms Test"));
+  t.throws(() => new MediaPlayer("xosms test", "Xosms Test"));
+});
+
+linuxTest("can activate on a bus address", (t) => {
+  const player = new MediaPlayer("xosmstestaddress", "Address", {
+    busType: MediaPlayerBusType.Address,
+    busAddress: process.env.DBUS_SESSION_BUS_ADDRESS,
+  });
+  t.is(player.activate(), "org.mpris.MediaPlayer2.xosmstestaddress");
+  player.deactivate();
+});
+
+linuxTest("can not create mediaplayer with invalid bus options", (t) => {
+  t.throws(() => new MediaPlayer("xosms", "Xosms Test", { busType: MediaPlayerBusType.Address }), {
+    code: XosmsErrorCode.XOSMS_INVALID_ARGUMENT,
+  });
+  t.throws(() => new MediaPlayer("xosms", "Xosms Test", { busType: MediaPlayerBusType.Private, busAddress: "" }), {
+    code: XosmsErrorCode.XOSMS_INVALID_ARGUMENT,
+  });
+  t.throws(() => new MediaPlayer("xosms", "Xosms Test", { busAddress: "unix:path=/tmp/xosms" }), {
+    code: XosmsErrorCode.XOSMS_INVALID_ARGUMENT,
+  });
+});
+
+linuxTest("unreachable bus addresses are reported", async (t) => {
+  const player = new MediaPlayer("xosmstestunreachable", "Unreachable", {
+    busType: MediaPlayerBusType.Address,
+    busAddress: "unix:path=/nonexistent/xosms",
+  });
+  const error = t.throws(() => player.activate(), { code: XosmsErrorCode.XOSMS_BUS_UNAVAILABLE });
+  t.is(error.details.bus, "bus at unix:path=/nonexistent/xosms");
+  t.is(typeof error.details.reason, "string");
+  await t.throwsAsync(() => player.activateAsync(), { code: XosmsErrorCode.XOSMS_BUS_UNAVAILABLE });
+  t.is(player.connectionState, MediaPlayerConnectionState.Disconnected);
+});
+
 test.serial("activate before rest of tests", (t) => {
   t.context.player.activate();
   t.pass();
@@ -136,6 +242,120 @@ test.serial("cannot set timeline position above duration", async (t) => {
   });
 });
 
+test.serial("can set timeline with seek bounds", async (t) => {
+  t.notThrows(() => {
+    t.context.player.setTimeline(60, 10, { startTime: 0, minSeekTime: 5, maxSeekTime: 50 });
+  });
+});
+
+test.serial("can set timeline without seek detection", async (t) => {
+  t.notThrows(() => {
+    t.context.player.setTimeline(60, 30, { detectSeek: false });
+  });
+});
+
+test.serial("can set timeline with seek threshold", async (t) => {
+  t.notThrows(() => {
+    t.context.player.setTimeline(60, 20, { seekThreshold: 5 });
+  });
+});
+
+test.serial("cannot set timeline start time above position", async (t) => {
+  t.throws(() => {
+    t.context.player.setTimeline(60, 10, { startTime: 20 });
+  });
+});
+
+test.serial("cannot set timeline max seek time above duration", async (t) => {
+  t.throws(() => {
+    t.context.player.setTimeline(60, 10, { maxSeekTime: 61 });
+  });
+});
+
+test.serial("cannot set timeline min seek time above max seek time", async (t) => {
+  t.throws(() => {
+    t.context.player.setTimeline(60, 10, { minSeekTime: 40, maxSeekTime: 30 });
+  });
+});
+
+test.serial("can report seek", async (t) => {
+  t.notThrows(() => t.context.player.seeked(15));
+});
+
+test.serial("cannot report seek below 0", async (t) => {
+  t.throws(() => t.context.player.seeked(-1));
+});
+
+linuxTest("cannot report seek outside the seek bounds", async (t) => {
+  t.context.player.setTimeline(60, 10, { minSeekTime: 5, maxSeekTime: 50 });
+  t.throws(() => t.context.player.seeked(4));
+  t.throws(() => t.context.player.seeked(55));
+  t.notThrows(() => t.context.player.seeked(50));
+});
+
+//
+// PLAYBACK CLOCK TESTS
+//
+test.serial("can get position", async (t) => {
+  t.notThrows(() => t.context.player.position);
+});
+
+test.serial("can set tick interval", async (t) => {
+  t.notThrows(() => (t.context.player.tickInterval = 0.5));
+});
+
+test.serial("can disable tick interval", async (t) => {
+  t.notThrows(() => (t.context.player.tickInterval = null));
+});
+
+test.serial("cannot set tick interval to 0", async (t) => {
+  t.throws(() => (t.context.player.tickInterval = 0));
+});
+
+test.serial("cannot set tick interval to Infinity", async (t) => {
+  t.throws(() => (t.context.player.tickInterval = Infinity), {
+    code: XosmsErrorCode.XOSMS_INVALID_ARGUMENT,
+  });
+});
+
+test.serial("can only advance clock with the mock backend", async (t) => {
+  t.throws(() => t.context.player.advanceClock(1), { code: XosmsErrorCode.XOSMS_INVALID_STATE });
+});
+
+test.serial("cannot advance clock by a negative amount", async (t) => {
+  const player = new MediaPlayer("xosmsclock", "Xosms Clock Test", { backend: MediaPlayerBackend.Mock });
+  t.throws(() => player.advanceClock(-1));
+});
+
+linuxTest("position extrapolates while playing", async (t) => {
+  const player = new MediaPlayer("xosmsclock", "Xosms Clock Test", { backend: MediaPlayerBackend.Mock });
+  player.setTimeline(60, 10);
+  player.playbackStatus = MediaPlayerPlaybackStatus.Playing;
+  player.advanceClock(5);
+  t.is(player.position, 15);
+
+  player.maximumPlaybackRate = 2;
+  player.playbackRate = 2;
+  player.advanceClock(5);
+  t.is(player.position, 25);
+
+  player.playbackStatus = MediaPlayerPlaybackStatus.Paused;
+  player.advanceClock(5);
+  t.is(player.position, 25);
+
+  player.playbackStatus = MediaPlayerPlaybackStatus.Playing;
+  player.advanceClock(60);
+  t.is(player.position, 60);
+});
+
+test.serial("can add tick event", (t) => {
+  t.notThrows(() => t.context.player.on("tick", () => {}));
+});
+
+test.serial("can add ended event", (t) => {
+  t.notThrows(() => t.context.player.on("ended", () => {}));
+});
+
 //
 // MEDIATYPE PROPERTY TESTS
 //
@@ -172,6 +392,40 @@ test.serial("can set playback rate to float", async (t) => {
   t.notThrows(() => t.context.player.playbackRate = 1.5);
 });
 
+test.serial("can set minimum playback rate", async (t) => {
+  t.notThrows(() => (t.context.player.minimumPlaybackRate = 0.5));
+});
+
+test.serial("cannot set minimum playback rate above 1", async (t) => {
+  t.throws(() => (t.context.player.minimumPlaybackRate = 1.5));
+});
+
+test.serial("can set maximum playback rate", async (t) => {
+  t.notThrows(() => (t.context.player.maximumPlaybackRate = 2));
+});
+
+test.serial("cannot set maximum playback rate below 1", async (t) => {
+  t.throws(() => (t.context.player.maximumPlaybackRate = 0.5));
+});
+
+linuxTest("playback rate is kept within its bounds", (t) => {
+  const player = new MediaPlayer("xosmstestrate", "Rate");
+  player.minimumPlaybackRate = 0.5;
+  player.maximumPlaybackRate = 2;
+  player.playbackRate = 3;
+  t.is(player.playbackRate, 2);
+  player.playbackRate = 0.25;
+  t.is(player.playbackRate, 0.5);
+  player.playbackRate = 2;
+  player.maximumPlaybackRate = 1.5;
+  t.is(player.playbackRate, 1.5);
+  t.throws(() => (player.playbackRate = NaN), { code: XosmsErrorCode.XOSMS_INVALID_ARGUMENT });
+});
+
+test.serial("can add ratechanged event", (t) => {
+  t.notThrows(() => t.context.player.addEventListener("ratechanged", () => {}));
+});
+
 //
 // PLAYBACKSTATUS PROPERTY TESTS
 //
@@ -193,6 +447,77 @@ test.serial("playback status before set is unknown", async (t) => {
   t.assert(() => t.context.player.playbackStatus == MediaPlayerPlaybackStatus.Unknown);
 });
 
+//
+// REPEAT MODE PROPERTY TESTS
+//
+test.serial("can get repeat mode before set", async (t) => {
+  t.notThrows(() => t.context.player.repeatMode);
+});
+
+test.serial("cannot set repeat mode to unknown", async (t) => {
+  t.throws(() => (t.context.player.repeatMode = MediaPlayerRepeatMode.Unknown));
+});
+
+test.serial("can set repeat mode to playlist", async (t) => {
+  t.notThrows(
+    () => (t.context.player.repeatMode = MediaPlayerRepeatMode.Playlist)
+  );
+});
+
+test.serial("can add repeatmodechanged event", (t) => {
+  t.notThrows(() => t.context.player.addEventListener("repeatmodechanged", () => {}));
+});
+
+test.serial("can remove repeatmodechanged event", (t) => {
+  const callback = () => {};
+  t.notThrows(() => {
+    t.context.player.addEventListener("repeatmodechanged", callback);
+    t.context.player.removeEventListener("repeatmodechanged", callback);
+  });
+});
+
+//
+// SHUFFLE PROPERTY TESTS
+//
+test.serial("can get shuffle before set", async (t) => {
+  t.notThrows(() => t.context.player.shuffle);
+});
+
+test.serial("can set shuffle", async (t) => {
+  t.notThrows(() => (t.context.player.shuffle = true));
+});
+
+test.serial("can add shufflechanged event", (t) => {
+  t.notThrows(() => t.context.player.addEventListener("shufflechanged", () => {}));
+});
+
+test.serial("can remove shufflechanged event", (t) => {
+  const callback = () => {};
+  t.notThrows(() => {
+    t.context.player.addEventListener("shufflechanged", callback);
+    t.context.player.removeEventListener("shufflechanged", callback);
+  });
+});
+
+//
+// VOLUME PROPERTY TESTS
+//
+test.serial("can get volume before set", async (t) => {
+  t.notThrows(() => t.context.player.volume);
+});
+
+test.serial("can set volume", async (t) => {
+  t.notThrows(() => (t.context.player.volume = 0.5));
+});
+
+test.serial("cannot set volume to NaN", async (t) => {
+  t.throws(() => (t.context.player.volume = NaN));
+});
+
+test.serial("can add volumechanged event", (t) => {
+  t.notThrows(() => t.context.player.addEventListener("volumechanged", () => {}));
+});
+
 //
 // BUTTON ENABLEMENT PROPERTY TESTS
 //
@@ -240,6 +565,65 @@ test.serial("can set next button enabled", async (t) => {
   t.notThrows(() => (t.context.player.nextButtonEnabled = true));
 });
 
+test.serial("can set identity", async (t) => {
+  t.notThrows(() => (t.context.player.identity = "Xosms Test"));
+});
+
+test.serial("can set desktop entry", async (t) => {
+  t.notThrows(() => (t.context.player.desktopEntry = "xosms"));
+});
+
+test.serial("can allow setting fullscreen", async (t) => {
+  t.notThrows(() => (t.context.player.canSetFullscreen = true));
+});
+
+test.serial("can set fullscreen", async (t) => {
+  t.notThrows(() => (t.context.player.fullscreen = true));
+});
+
+test.serial("can add fullscreenchanged event", (t) => {
+  t.notThrows(() => t.context.player.addEventListener("fullscreenchanged", () => {}));
+});
+
+test.serial("can get raise enabled before set", async (t) => {
+  t.notThrows(() => t.context.player.raiseEnabled);
+});
+
+test.serial("can set raise enabled", async (t) => {
+  t.notThrows(() => (t.context.player.raiseEnabled = true));
+});
+
+test.serial("can get quit enabled before set", async (t) => {
+  t.notThrows(() => t.context.player.quitEnabled);
+});
+
+test.serial("can set quit enabled", async (t) => {
+  t.notThrows(() => (t.context.player.quitEnabled = true));
+});
+
+test.serial("can add raise event", (t) => {
+  t.notThrows(() => t.context.player.addEventListener("raise", () => {}));
+});
+
+test.serial("can add quit event", (t) => {
+  t.notThrows(() => t.context.player.addEventListener("quit", () => {}));
+});
+
+//
+// OPEN URI PROPERTY TESTS
+//
+test.serial("can set supported uri schemes", async (t) => {
+  t.notThrows(() => (t.context.player.supportedUriSchemes = ["file", "https"]));
+});
+
+test.serial("can set supported mime types", async (t) => {
+  t.notThrows(() => (t.context.player.supportedMimeTypes = ["audio/mpeg"]));
+});
+
+test.serial("can add openuri event", (t) => {
+  t.notThrows(() => t.context.player.addEventListener("openuri", () => {}));
+});
+
 //
 // TITLE PROPERTY TESTS
 //
@@ -285,6 +669,77 @@ test.serial("can set album title", async (t) => {
   t.notThrows(() => (t.context.player.albumTitle = "Test Artist"));
 });
 
+//
+// EXTENDED METADATA PROPERTY TESTS
+//
+test.serial("can set artists", async (t) => {
+  t.notThrows(() => (t.context.player.artists = ["Test Artist", "Test Artist 2"]));
+});
+
+test.serial("can set album artists", async (t) => {
+  t.notThrows(() => (t.context.player.albumArtists = ["Test Album Artist"]));
+});
+
+test.serial("can set genres", async (t) => {
+  t.notThrows(() => (t.context.player.genres = ["Test Genre"]));
+});
+
+test.serial("can set track number", async (t) => {
+  t.notThrows(() => (t.context.player.trackNumber = 1));
+});
+
+test.serial("can clear track number", async (t) => {
+  t.notThrows(() => (t.context.player.trackNumber = null));
+});
+
+test.serial("cannot set track number below 0", async (t) => {
+  t.throws(() => (t.context.player.trackNumber = -1));
+});
+
+test.serial("can set user rating", async (t) => {
+  t.notThrows(() => (t.context.player.userRating = 0.5));
+});
+
+test.serial("cannot set user rating above 1", async (t) => {
+  t.throws(() => (t.context.player.userRating = 1.5));
+});
+
+test.serial("can set content created date", async (t) => {
+  t.notThrows(() => (t.context.player.contentCreated = Date.now()));
+});
+
+//
+// CUSTOM METADATA TESTS
+//
+test.serial("can set custom metadata", async (t) => {
+  t.notThrows(() => {
+    t.context.player.setCustomMetadata("xosms:string", "Test");
+    t.context.player.setCustomMetadata("xosms:integer", 1);
+    t.context.player.setCustomMetadata("xosms:double", 1.5);
+    t.context.player.setCustomMetadata("xosms:boolean", true);
+    t.context.player.setCustomMetadata("xosms:strings", ["Test", "Test 2"]);
+    t.context.player.setCustomMetadata("xosms:date", new Date());
+  });
+});
+
+test.serial("cannot set custom metadata with malformed key", async (t) => {
+  t.throws(() => t.context.player.setCustomMetadata("xosms", "Test"));
+});
+
+test.serial("cannot set custom metadata in mpris namespace", async (t) => {
+  t.throws(() => t.context.player.setCustomMetadata("mpris:trackid", "Test"));
+});
+
+test.serial("cannot set custom metadata owned by a property", async (t) => {
+  t.throws(() => t.context.player.setCustomMetadata("xesam:artist", "Test"), {
+    code: XosmsErrorCode.XOSMS_INVALID_ARGUMENT,
+  });
+});
+
+test.serial("can remove custom metadata", async (t) => {
+  t.notThrows(() => t.context.player.removeCustomMetadata("xosms:string"));
+});
+
 //
 // TRACK ID PROPERTY TESTS
 //
@@ -300,9 +755,302 @@ test.serial("can set track id", async (t) => {
   t.notThrows(() => (t.context.player.trackId = "TestTrackId"));
 });
 
+//
+// TRACK LIST TESTS
+//
+test.serial("can get track list before set", async (t) => {
+  t.notThrows(() => t.context.player.trackList);
+});
+
+test.serial("can set track list", async (t) => {
+  t.notThrows(() =>
+    t.context.player.setTrackList(
+      [
+        { trackId: "TestTrackId", title: "Test Title", duration: 60 },
+        { trackId: "TestTrackId2", title: "Test Title 2" },
+      ],
+      "TestTrackId"
+    )
+  );
+});
+
+linuxTest("cannot set track list with a current track id which is not in it", async (t) => {
+  t.throws(
+    () => t.context.player.setTrackList([{ trackId: "TestTrackId" }], "TestTrackId2"),
+    { code: XosmsErrorCode.XOSMS_NOT_FOUND }
+  );
+});
+
+test.serial("can add track", async (t) => {
+  t.notThrows(() =>
+    t.context.player.addTrack({ trackId: "TestTrackId3" }, "TestTrackId")
+  );
+});
+
+test.serial("can update track", async (t) => {
+  t.notThrows(() =>
+    t.context.player.updateTrack({ trackId: "TestTrackId3", title: "Test Title 3" })
+  );
+});
+
+test.serial("can remove track", async (t) => {
+  t.notThrows(() => t.context.player.removeTrack("TestTrackId3"));
+});
+
+test.serial("can set can edit tracks", async (t) => {
+  t.notThrows(() => (t.context.player.canEditTracks = true));
+});
+
+test.serial("can add trackgoto event", (t) => {
+  t.notThrows(() => t.context.player.on("trackgoto", () => {}));
+});
+
+test.serial("can remmove trackgoto event", (t) => {
+  t.notThrows(() => {
+    let listener = () => {};
+    t.context.player.on("trackgoto", listener);
+    t.context.player.off("trackgoto", listener);
+  });
+});
+
+test.serial("can add trackadd event", (t) => {
+  t.notThrows(() => t.context.player.on("trackadd", () => {}));
+});
+
+test.serial("can add trackremove event", (t) => {
+  t.notThrows(() => t.context.player.on("trackremove", () => {}));
+});
+
+//
+// PLAYLISTS TESTS
+//
+test.serial("can get playlists before set", async (t) => {
+  t.notThrows(() => t.context.player.playlists);
+});
+
+test.serial("can set playlists", async (t) => {
+  t.notThrows(() =>
+    t.context.player.setPlaylists([
+      { playlistId: "TestPlaylistId", name: "Test Playlist" },
+      { playlistId: "TestPlaylistId2", name: "Test Playlist 2", creationDate: Date.now() },
+    ])
+  );
+});
+
+test.serial("can update playlist", async (t) => {
+  t.notThrows(() =>
+    t.context.player.updatePlaylist({ playlistId: "TestPlaylistId", name: "Renamed Playlist" })
+  );
+});
+
+test.serial("can set active playlist", async (t) => {
+  t.notThrows(() => (t.context.player.activePlaylistId = "TestPlaylistId"));
+});
+
+test.serial("can set playlist orderings", async (t) => {
+  t.notThrows(
+    () =>
+      (t.context.player.playlistOrderings = [
+        MediaPlayerPlaylistOrdering.Alphabetical,
+        MediaPlayerPlaylistOrdering.UserDefined,
+      ])
+  );
+});
+
+test.serial("cannot set playlist orderings to unknown", async (t) => {
+  t.throws(
+    () => (t.context.player.playlistOrderings = [MediaPlayerPlaylistOrdering.Unknown])
+  );
+});
+
+test.serial("can add playlistactivated event", (t) => {
+  t.notThrows(() => t.context.player.on("playlistactivated", () => {}));
+});
+
+//
+// BULK STATE TESTS
+//
+test.serial("can set state", async (t) => {
+  let thumbnail = await MediaPlayerThumbnail.create(
+    MediaPlayerThumbnailType.Uri,
+    "https://via.placeholder.com/1.png"
+  );
+  t.notThrows(() =>
+    t.context.player.setState({
+      title: "Bulk Title",
+      artists: ["Bulk Artist"],
+      albumTitle: "Bulk Album",
+      trackId: "BulkTrackId",
+      duration: 120,
+      position: 10,
+      thumbnail,
+      playbackStatus: MediaPlayerPlaybackStatus.Playing,
+      capabilities: { playButtonEnabled: true, pauseButtonEnabled: true, seekEnabled: true },
+    })
+  );
+});
+
+test.serial("cannot set state with position above duration", async (t) => {
+  t.throws(() => t.context.player.setState({ title: "Invalid", duration: 10, position: 20 }));
+});
+
+test.serial("cannot set state with unknown playback status", async (t) => {
+  t.throws(() =>
+    t.context.player.setState({ playbackStatus: MediaPlayerPlaybackStatus.Unknown })
+  );
+});
+
+linuxTest("invalid state leaves state untouched", async (t) => {
+  t.throws(() => t.context.player.setState({ title: "Invalid", trackId: "invalid-id" }));
+  t.is(t.context.player.title, "Bulk Title");
+});
+
+linuxTest("set state keeps the timeline seek bounds", async (t) => {
+  t.context.player.setTimeline(60, 10, { minSeekTime: 5, maxSeekTime: 50 });
+  t.context.player.setState({ position: 20 });
+  t.throws(() => t.context.player.seeked(55));
+  t.context.player.setState({ position: 20, timelineOptions: { maxSeekTime: 60 } });
+  t.notThrows(() => t.context.player.seeked(55));
+});
+
 //
 // UPDATE TESTS
 //
 test.serial("can run update", async (t) => {
   t.notThrows(() => t.context.player.update());
-});
\ No newline at end of file
+});
+
+test.serial("can run update without changes", async (t) => {
+  t.notThrows(() => {
+    t.context.player.update();
+    t.context.player.update();
+  });
+});
+
+test.serial("can flush now", async (t) => {
+  t.notThrows(() => t.context.player.flushNow());
+});
+
+test.serial("can enable auto flush", async (t) => {
+  t.notThrows(() => (t.context.player.autoFlushDebounce = 0.05));
+});
+
+test.serial("can disable auto flush", async (t) => {
+  t.notThrows(() => (t.context.player.autoFlushDebounce = null));
+});
+
+test.serial("cannot set auto flush debounce below 0", async (t) => {
+  t.throws(() => (t.context.player.autoFlushDebounce = -1));
+});
+
+test.serial("cannot set auto flush debounce to Infinity", async (t) => {
+  t.throws(() => (t.context.player.autoFlushDebounce = Infinity));
+});
+//
+// ERROR CODE TESTS
+//
+test.serial("invalid enum errors have a code and details", async (t) => {
+  const error = t.throws(
+    () => (t.context.player.playbackStatus = MediaPlayerPlaybackStatus.Unknown),
+    { code: XosmsErrorCode.XOSMS_INVALID_ENUM }
+  );
+  t.deepEqual(error.details, {
+    value: MediaPlayerPlaybackStatus.Unknown,
+    enum: "MediaPlayerPlaybackStatus",
+  });
+});
+
+test.serial("invalid timeline errors have a code and details", async (t) => {
+  const error = t.throws(() => t.context.player.setTimeline(60, 61), {
+    code: XosmsErrorCode.XOSMS_INVALID_TIMELINE,
+  });
+  t.deepEqual(error.details, { position: 61, duration: 60 });
+});
+
+test.serial("invalid argument errors have a code and details", async (t) => {
+  const error = t.throws(() => (t.context.player.autoFlushDebounce = -1), {
+    code: XosmsErrorCode.XOSMS_INVALID_ARGUMENT,
+  });
+  t.deepEqual(error.details, { value: -1 });
+});
+
+linuxTest("taken service name errors have a code", async (t) => {
+  const first = new MediaPlayer("xosmstesttaken", "First");
+  const second = new MediaPlayer("xosmstesttaken", "Second");
+  first.activate();
+  const error = t.throws(() => second.activate(), { code: XosmsErrorCode.XOSMS_NAME_TAKEN });
+  t.is(error.details.serviceName, "org.mpris.MediaPlayer2.xosmstesttaken");
+  await t.throwsAsync(() => second.activateAsync(), { code: XosmsErrorCode.XOSMS_NAME_TAKEN });
+  first.deactivate();
+});
+
+//
+// MOCK BACKEND TESTS
+//
+const nextEvent = (player, eventName) =>
+  new Promise((resolve) => player.on(eventName, (error, value) => resolve(value)));
+
+test.serial("can create mock mediaplayer", (t) => {
+  t.notThrows(() => new MediaPlayer("xosmstestmock", "Mock", { backend: MediaPlayerBackend.Mock }));
+});
+
+test.serial("simulate functions require the mock backend", (t) => {
+  const player = new MediaPlayer("xosmstestnative", "Native", { backend: MediaPlayerBackend.Native });
+  t.throws(() => player.simulateButton("next"), { code: XosmsErrorCode.XOSMS_INVALID_STATE });
+  t.throws(() => player.simulateSeek(-5), { code: XosmsErrorCode.XOSMS_INVALID_STATE });
+  t.throws(() => player.publishedState(), { code: XosmsErrorCode.XOSMS_INVALID_STATE });
+});
+
+linuxTest("simulated button presses emit buttonpressed", async (t) => {
+  const player = new MediaPlayer("xosmstestmockbutton", "Mock", { backend: MediaPlayerBackend.Mock });
+  player.activate();
+  player.nextButtonEnabled = true;
+  const pressed = nextEvent(player, "buttonpressed");
+  // Disabled buttons are ignored like they are by the operating system
+  player.simulateButton("previous");
+  player.simulateButton("next");
+  t.is(await pressed, "next");
+  t.throws(() => player.simulateButton("eject"), { code: XosmsErrorCode.XOSMS_INVALID_ARGUMENT });
+  player.deactivate();
+});
+
+linuxTest("simulated seeks emit position events", async (t) => {
+  const player = new MediaPlayer("xosmstestmockseek", "Mock", { backend: MediaPlayerBackend.Mock });
+  player.activate();
+  player.seekEnabled = true;
+  player.trackId = "MockTrackId";
+  player.setTimeline(60, 30);
+  const changed = nextEvent(player, "positionchanged");
+  const seeked = nextEvent(player, "positionseeked");
+  player.simulatePositionChange(12.5);
+  player.simulateSeek(-5);
+  t.is(await changed, 12.5);
+  t.is(await seeked, -5);
+  player.deactivate();
+});
+
+linuxTest("simulated position change without a track id emits a position event", async (t) => {
+  const player = new MediaPlayer("xosmstestmocknotrack", "Mock", { backend: MediaPlayerBackend.Mock });
+  player.activate();
+  player.seekEnabled = true;
+  player.setTimeline(60, 30);
+  const changed = nextEvent(player, "positionchanged");
+  player.simulatePositionChange(12.5);
+  t.is(await changed, 12.5);
+  player.deactivate();
+});
+
+linuxTest("published state follows activation", (t) => {
+  const player = new MediaPlayer("xosmstestmockstate", "Mock", { backend: MediaPlayerBackend.Mock });
+  t.is(player.publishedState(), null);
+  player.activate();
+  player.title = "Mock Title";
+  player.playbackStatus = MediaPlayerPlaybackStatus.Playing;
+  const state = player.publishedState();
+  t.is(state.serviceName, "org.mpris.MediaPlayer2.xosmstestmockstate");
+  t.is(state.title, "Mock Title");
+  t.is(state.playbackStatus, MediaPlayerPlaybackStatus.Playing);
+  t.false(state.capabilities.nextButtonEnabled);
+  player.deactivate();
+  t.is(player.publishedState(), null);
+});
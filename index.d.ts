@@ -18,48 +18,345 @@ export const enum MediaPlayerPlaybackStatus {
   Paused = 2,
   Stopped = 3
 }
+export const enum MediaPlayerRepeatMode {
+  Unknown = -1,
+  None = 1,
+  Track = 2,
+  Playlist = 3
+}
+export const enum MediaPlayerPlaylistOrdering {
+  Unknown = -1,
+  Alphabetical = 1,
+  CreationDate = 2,
+  ModifiedDate = 3,
+  LastPlayDate = 4,
+  UserDefined = 5
+}
+export const enum MediaPlayerConnectionState {
+  Disconnected = 1,
+  Connected = 2,
+  Reconnecting = 3
+}
+export const enum MediaPlayerBusType {
+  Session = 1,
+  System = 2,
+  /** Message bus listening on the bus address, in the format of `DBUS_SESSION_BUS_ADDRESS` */
+  Address = 3,
+  /** Peer-to-peer connection to the bus address, without a message bus owning the service name */
+  Private = 4
+}
+export const enum MediaPlayerBackend {
+  /** Media services of the operating system */
+  Native = 1,
+  /** Keeps the state in memory without the operating system seeing it, driven by the simulate functions */
+  Mock = 2
+}
+/** Stable codes set as the `code` of the errors thrown by xosms */
+export const enum XosmsErrorCode {
+  /** The service name is owned by another process */
+  XOSMS_NAME_TAKEN = 'XOSMS_NAME_TAKEN',
+  /** The bus could not be connected to */
+  XOSMS_BUS_UNAVAILABLE = 'XOSMS_BUS_UNAVAILABLE',
+  /** The timeline, position or duration are inconsistent */
+  XOSMS_INVALID_TIMELINE = 'XOSMS_INVALID_TIMELINE',
+  /** An enum value which can not be used, such as Unknown */
+  XOSMS_INVALID_ENUM = 'XOSMS_INVALID_ENUM',
+  /** Waiting for the session bus took longer than the timeout */
+  XOSMS_TIMEOUT = 'XOSMS_TIMEOUT',
+  /** An argument is out of range or malformed */
+  XOSMS_INVALID_ARGUMENT = 'XOSMS_INVALID_ARGUMENT',
+  /** A track or playlist id is not known */
+  XOSMS_NOT_FOUND = 'XOSMS_NOT_FOUND',
+  /** A track or playlist id is used more than once */
+  XOSMS_DUPLICATE_ID = 'XOSMS_DUPLICATE_ID',
+  /** The media player is not in a state which allows the call */
+  XOSMS_INVALID_STATE = 'XOSMS_INVALID_STATE',
+  /** The media services of the operating system failed */
+  XOSMS_PLATFORM_ERROR = 'XOSMS_PLATFORM_ERROR'
+}
+/** Error thrown by xosms with a stable code and details about the failure, such as the offending value */
+export interface XosmsError extends Error {
+  code: XosmsErrorCode
+  details: Record<string, string | number | boolean>
+}
+export interface MediaPlayerTrack {
+  /** Unique id of the track within the track list */
+  trackId: string
+  /** Title of the track */
+  title?: string
+  /** Artist of the track */
+  artist?: string
+  /** Album title of the track */
+  albumTitle?: string
+  /** Duration of the track in seconds */
+  duration?: number
+  /** Thumbnail uri of the track */
+  thumbnail?: string
+}
+export interface MediaPlayerPlaylist {
+  /** Unique id of the playlist */
+  playlistId: string
+  /** Name of the playlist */
+  name: string
+  /** Icon uri of the playlist */
+  icon?: string
+  /** Creation date of the playlist in milliseconds since the unix epoch */
+  creationDate?: number
+  /** Last modified date of the playlist in milliseconds since the unix epoch */
+  modifiedDate?: number
+  /** Last played date of the playlist in milliseconds since the unix epoch */
+  lastPlayDate?: number
+}
+export interface MediaPlayerTimelineOptions {
+  /** Start time of the media in seconds, defaults to 0 */
+  startTime?: number
+  /** Earliest position in seconds which can be seeked to, defaults to the start time */
+  minSeekTime?: number
+  /** Latest position in seconds which can be seeked to, defaults to the duration */
+  maxSeekTime?: number
+  /** Whether to emit a seeked signal when the position jumps away from the expected position, defaults to true */
+  detectSeek?: boolean
+  /** Amount of seconds the position may differ from the expected position before it is considered a seek, defaults to 1 */
+  seekThreshold?: number
+}
+export interface MediaPlayerOptions {
+  /** Backend to use, defaults to the XOSMS_BACKEND environment variable or else Native. The bus options are ignored by the Mock backend */
+  backend?: MediaPlayerBackend
+  /** Bus to serve the media player on, defaults to Session. Only used on Linux */
+  busType?: MediaPlayerBusType
+  /** Address such as `unix:path=/run/user/1000/bus`, required by the Address and Private bus types */
+  busAddress?: string
+}
+export interface MediaPlayerActivateOptions {
+  /** Whether another process may take over the service name, defaults to false */
+  allowReplacement?: boolean
+  /** Whether to take over the service name if its current owner allows replacement, defaults to true */
+  replaceExisting?: boolean
+  /** Whether to wait in the queue for the service name when it is taken instead of failing, defaults to false */
+  queue?: boolean
+  /** Whether to fall back to the service name suffixed with `.instance<pid>` when it is taken, defaults to false */
+  instanceFallback?: boolean
+  /** Seconds to wait for the session bus before failing, defaults to 1 */
+  timeout?: number
+}
+export interface MediaPlayerActivation {
+  /** The service name which was acquired, or queued for */
+  serviceName: string
+  /** Whether the service name was queued for as it is owned by another process */
+  queued: boolean
+}
+export interface MediaPlayerCapabilities {
+  /** Play button enabled state */
+  playButtonEnabled?: boolean
+  /** Pause button enabled state */
+  pauseButtonEnabled?: boolean
+  /** Stop button enabled state */
+  stopButtonEnabled?: boolean
+  /** Previous button enabled state */
+  previousButtonEnabled?: boolean
+  /** Next button enabled state */
+  nextButtonEnabled?: boolean
+  /** Seek enabled state */
+  seekEnabled?: boolean
+}
+export interface MediaPlayerPublishedState {
+  /** The service name which was acquired */
+  serviceName: string
+  /** Playback status */
+  playbackStatus: MediaPlayerPlaybackStatus
+  /** Media title */
+  title: string
+  /** Media artists */
+  artists: Array<string>
+  /** Media album title */
+  albumTitle: string
+  /** Track id */
+  trackId: string
+  /** Position in seconds */
+  position: number
+  /** Playback rate */
+  playbackRate: number
+  /** Volume */
+  volume: number
+  /** Repeat mode */
+  repeatMode: MediaPlayerRepeatMode
+  /** Shuffle state */
+  shuffle: boolean
+  /** Button and seek enabled states */
+  capabilities: MediaPlayerCapabilities
+}
+export interface MediaPlayerState {
+  /** Media title */
+  title?: string
+  /** Media artists */
+  artists?: Array<string>
+  /** Media album title */
+  albumTitle?: string
+  /** Track id of the media */
+  trackId?: string
+  /** Duration of the media in seconds */
+  duration?: number
+  /** Position of the media in seconds */
+  position?: number
+  /** Thumbnail of the media */
+  thumbnail?: MediaPlayerThumbnail
+  /** Playback status */
+  playbackStatus?: MediaPlayerPlaybackStatus
+  /** Button enabled states */
+  capabilities?: MediaPlayerCapabilities
+  /** Timeline options applied with the duration and position, defaults to the options of the last timeline update */
+  timelineOptions?: MediaPlayerTimelineOptions
+}
 export class MediaPlayerThumbnail {
   static create(thumbnailType: MediaPlayerThumbnailType, thumbnail: string): Promise<MediaPlayerThumbnail>
   get type(): MediaPlayerThumbnailType
 }
 export class MediaPlayer {
-  constructor(serviceName: string, identity: string)
-  /** Activates the MediaPlayer allowing the operating system to see and use it */
-  activate(): void
+  constructor(serviceName: string, identity: string, options?: MediaPlayerOptions | undefined | null)
+  /**
+   * Activates the MediaPlayer allowing the operating system to see and use it
+   *
+   * Returns the service name which was acquired, or queued for when queueing is enabled and the name is taken. A 'nameacquired' event is emitted once a queued name is acquired.
+   */
+  activate(options?: MediaPlayerActivateOptions | undefined | null): string
+  /**
+   * Activates the MediaPlayer without blocking while waiting for the session bus
+   *
+   * Resolves with the service name which was acquired, or queued for, once the session bus responds.
+   */
+  activateAsync(options?: MediaPlayerActivateOptions | undefined | null): Promise<MediaPlayerActivation>
+  /**
+   * Deactivates the MediaPlayer without blocking while waiting for the session bus
+   *
+   * Resolves once the service name has been released, waiting for at most the timeout in seconds which defaults to 1.
+   */
+  deactivateAsync(timeout?: number | undefined | null): Promise<void>
   /** Deactivates the MediaPlayer denying the operating system to see and use it */
   deactivate(): void
+  /**
+   * Gets the state of the connection to the session bus
+   *
+   * While reconnecting the media player is not visible to the operating system. Its name is requested again and its state re-broadcast once the bus is back.
+   */
+  get connectionState(): MediaPlayerConnectionState
+  /**
+   * Simulates a media services button press, only available with the mock backend
+   *
+   * Presses are handled like they are from the operating system, so they are ignored while the media player is not activated or the button is disabled.
+   */
+  simulateButton(button: 'play' | 'pause' | 'playpause' | 'stop' | 'next' | 'previous'): void
+  /**
+   * Simulates the media service requesting a position change in seconds for the current track, only available with the mock backend
+   *
+   * Positions outside of the seekable range are ignored, as is the request while the media player is not activated or seeking is disabled.
+   */
+  simulatePositionChange(position: number): void
+  /**
+   * Simulates the media service requesting a seek by the offset in seconds from the current position, only available with the mock backend
+   *
+   * The request is ignored while the media player is not activated or seeking is disabled.
+   */
+  simulateSeek(offset: number): void
+  /**
+   * Gets a snapshot of the state visible to the operating system, only available with the mock backend
+   *
+   * Returns null while the media player is not activated.
+   */
+  publishedState(): MediaPlayerPublishedState | null
   /**
    * Adds an event listener to the MediaPlayer
    *
    * 'buttonpressed' - Emitted when a media services button is pressed
    * 'positionchanged' - Emitted when the media service requests a position change
    * 'positionseeked' - Emitted when the media service requests a forward or backward position seek from current position
+   * 'trackgoto' - Emitted when the media service requests to skip to a track in the track list
+   * 'trackadd' - Emitted when the media service requests a uri to be added to the track list
+   * 'trackremove' - Emitted when the media service requests a track to be removed from the track list
+   * 'playlistactivated' - Emitted when the media service requests a playlist to be activated
+   * 'repeatmodechanged' - Emitted when the media service requests a repeat mode change
+   * 'shufflechanged' - Emitted when the media service requests shuffle to be enabled or disabled
+   * 'volumechanged' - Emitted when the media service requests a volume change
+   * 'ratechanged' - Emitted when the media service requests a playback rate change
+   * 'raise' - Emitted when the media service requests the media player to be brought to the front
+   * 'quit' - Emitted when the media service requests the media player to quit
+   * 'openuri' - Emitted when the media service requests a uri to be opened
+   * 'fullscreenchanged' - Emitted when the media service requests fullscreen to be enabled or disabled
+   * 'tick' - Emitted every tick interval with the current position while playing
+   * 'ended' - Emitted when the position reaches the duration while playing, requires a tick interval
+   * 'busdisconnected' - Emitted when the connection to the session bus is lost while active
+   * 'busreconnected' - Emitted when the media player is visible again after the connection to the session bus was restored
+   * 'nameacquired' - Emitted with the service name when a queued for service name is acquired
+   * 'namelost' - Emitted with the service name when another process takes over the service name
    */
-  addEventListener(eventName: 'buttonpressed' | 'positionchanged' | 'positionseeked', callback: (...args: any[]) => any): void
+  addEventListener(eventName: 'buttonpressed' | 'positionchanged' | 'positionseeked' | 'trackgoto' | 'trackadd' | 'trackremove' | 'playlistactivated' | 'repeatmodechanged' | 'shufflechanged' | 'volumechanged' | 'ratechanged' | 'raise' | 'quit' | 'openuri' | 'fullscreenchanged' | 'tick' | 'ended' | 'busdisconnected' | 'busreconnected' | 'nameacquired' | 'namelost', callback: (...args: any[]) => any): void
   /** Removes an event listener from the MediaPlayer */
-  removeEventListener(eventName: 'buttonpressed' | 'positionchanged' | 'positionseeked', callback: (...args: any[]) => any): void
+  removeEventListener(eventName: 'buttonpressed' | 'positionchanged' | 'positionseeked' | 'trackgoto' | 'trackadd' | 'trackremove' | 'playlistactivated' | 'repeatmodechanged' | 'shufflechanged' | 'volumechanged' | 'ratechanged' | 'raise' | 'quit' | 'openuri' | 'fullscreenchanged' | 'tick' | 'ended' | 'busdisconnected' | 'busreconnected' | 'nameacquired' | 'namelost', callback: (...args: any[]) => any): void
   /**
    * Adds an event listener to the MediaPlayer
    *
    * Alias for addEventListener
    */
-  on(eventName: 'buttonpressed' | 'positionchanged' | 'positionseeked', callback: (...args: any[]) => any): void
+  on(eventName: 'buttonpressed' | 'positionchanged' | 'positionseeked' | 'trackgoto' | 'trackadd' | 'trackremove' | 'playlistactivated' | 'repeatmodechanged' | 'shufflechanged' | 'volumechanged' | 'ratechanged' | 'raise' | 'quit' | 'openuri' | 'fullscreenchanged' | 'tick' | 'ended' | 'busdisconnected' | 'busreconnected' | 'nameacquired' | 'namelost', callback: (...args: any[]) => any): void
   /**
    * Removes an event listener from the MediaPlayer
    *
    * Alias for removeEventListener
    */
-  off(eventName: 'buttonpressed' | 'positionchanged' | 'positionseeked', callback: (...args: any[]) => any): void
+  off(eventName: 'buttonpressed' | 'positionchanged' | 'positionseeked' | 'trackgoto' | 'trackadd' | 'trackremove' | 'playlistactivated' | 'repeatmodechanged' | 'shufflechanged' | 'volumechanged' | 'ratechanged' | 'raise' | 'quit' | 'openuri' | 'fullscreenchanged' | 'tick' | 'ended' | 'busdisconnected' | 'busreconnected' | 'nameacquired' | 'namelost', callback: (...args: any[]) => any): void
   /** Instructs the media service to update its media information being displayed */
   update(): void
+  /** Immediately emits the accumulated changes without waiting for the auto flush debounce window */
+  flushNow(): void
+  /** Gets the auto flush debounce window in seconds */
+  get autoFlushDebounce(): number | null
+  /**
+   * Sets the auto flush debounce window in seconds
+   *
+   * When set, accumulated changes are emitted automatically once the debounce window after the first change has passed. When null, changes are only emitted on update.
+   */
+  set autoFlushDebounce(autoFlushDebounce: number | null)
+  /**
+   * Sets multiple properties at once
+   *
+   * Every provided value is validated before any of them are applied so a validation failure leaves the state untouched. Omitted values are left unchanged.
+   *
+   * The changes are queued as a single PropertiesChanged which is emitted on the next update. A seeked signal is emitted when the position jumps by more than the seek threshold of the timeline options, unless seek detection is turned off.
+   */
+  setState(state: MediaPlayerState): void
   /** Sets the thumbnail */
   setThumbnail(thumbnail: MediaPlayerThumbnail): void
   /**
    * Sets the timeline data
    *
-   * You MUST call this function everytime the position changes in the song. The media service will become out of sync if this is not called enough or cause seeked signals to be emitted to the media service unnecessarily.
+   * The position is advanced automatically while the playback status is playing, scaled by the playback rate and capped at the duration. You only need to call this function when the position changes in any other way, such as when the track changes. Seeks should be reported with seeked.
+   *
+   * A seeked signal is still emitted when the position differs from the expected position by more than the seek threshold, unless seek detection is turned off in the options. A change of duration is treated as a new track and never reported as a seek.
+   *
+   * Without options the options of the previous timeline are kept.
+   */
+  setTimeline(duration: number, position: number, options?: MediaPlayerTimelineOptions | undefined | null): void
+  /** Reports that a seek occurred and immediately emits a seeked signal with the new position in seconds */
+  seeked(position: number): void
+  /**
+   * Gets the current position in seconds
+   *
+   * The position is extrapolated from the last timeline update while playing
+   */
+  get position(): number
+  /** Gets the interval in seconds between 'tick' events */
+  get tickInterval(): number | null
+  /**
+   * Sets the interval in seconds between 'tick' events
+   *
+   * The 'tick' and 'ended' events are only emitted while a tick interval is set
+   */
+  set tickInterval(tickInterval: number | null)
+  /**
+   * Advances the playback clock by the provided amount of seconds, only available with the mock backend
+   *
+   * The playback clock of the mock backend only moves when advanced
    */
-  setTimeline(duration: number, position: number): void
+  advanceClock(seconds: number): void
   /** Gets the play button enbled state */
   get playButtonEnabled(): boolean
   /** Sets the play button enbled state */
@@ -84,14 +381,86 @@ export class MediaPlayer {
   get seekEnabled(): boolean
   /** Sets the seek enbled state */
   set seekEnabled(enabled: boolean)
+  /** Gets the identity of the media player */
+  get identity(): string
+  /** Sets the identity of the media player */
+  set identity(identity: string)
+  /** Gets the desktop entry name of the media player without the .desktop extension */
+  get desktopEntry(): string
+  /** Sets the desktop entry name of the media player without the .desktop extension */
+  set desktopEntry(desktopEntry: string)
+  /** Gets the fullscreen state */
+  get fullscreen(): boolean
+  /** Sets the fullscreen state */
+  set fullscreen(enabled: boolean)
+  /** Gets whether the fullscreen state can be set */
+  get canSetFullscreen(): boolean
+  /** Sets whether the fullscreen state can be set */
+  set canSetFullscreen(enabled: boolean)
+  /** Gets the raise enabled state */
+  get raiseEnabled(): boolean
+  /** Sets the raise enabled state */
+  set raiseEnabled(enabled: boolean)
+  /** Gets the quit enabled state */
+  get quitEnabled(): boolean
+  /** Sets the quit enabled state */
+  set quitEnabled(enabled: boolean)
+  /** Gets the uri schemes that can be opened */
+  get supportedUriSchemes(): Array<string>
+  /**
+   * Sets the uri schemes that can be opened
+   *
+   * Only uris with one of these schemes will be emitted through the 'openuri' event
+   */
+  set supportedUriSchemes(supportedUriSchemes: Array<string>)
+  /** Gets the mime types that can be opened */
+  get supportedMimeTypes(): Array<string>
+  /** Sets the mime types that can be opened */
+  set supportedMimeTypes(supportedMimeTypes: Array<string>)
   /** Gets the playback rate */
   get playbackRate(): number
-  /** Sets the playback rate */
+  /**
+   * Sets the playback rate
+   *
+   * The playback rate is clamped between the minimum and maximum playback rate
+   */
   set playbackRate(playbackRate: number)
+  /** Gets the minimum playback rate */
+  get minimumPlaybackRate(): number
+  /**
+   * Sets the minimum playback rate
+   *
+   * The minimum playback rate must be greater than 0.0 and at most 1.0
+   */
+  set minimumPlaybackRate(minimumPlaybackRate: number)
+  /** Gets the maximum playback rate */
+  get maximumPlaybackRate(): number
+  /**
+   * Sets the maximum playback rate
+   *
+   * The maximum playback rate must be at least 1.0
+   */
+  set maximumPlaybackRate(maximumPlaybackRate: number)
   /** Gets the playback status */
   get playbackStatus(): MediaPlayerPlaybackStatus
   /** Sets the playback status */
   set playbackStatus(playbackStatus: MediaPlayerPlaybackStatus)
+  /** Gets the repeat mode */
+  get repeatMode(): MediaPlayerRepeatMode
+  /** Sets the repeat mode */
+  set repeatMode(repeatMode: MediaPlayerRepeatMode)
+  /** Gets the shuffle state */
+  get shuffle(): boolean
+  /** Sets the shuffle state */
+  set shuffle(shuffle: boolean)
+  /** Gets the volume */
+  get volume(): number
+  /**
+   * Sets the volume
+   *
+   * The volume is clamped between 0.0 and 1.0
+   */
+  set volume(volume: number)
   /** Gets the media type */
   get mediaType(): MediaPlayerMediaType
   /** Sets the media type */
@@ -100,16 +469,162 @@ export class MediaPlayer {
   get title(): string
   /** Sets the media title */
   set title(title: string)
-  /** Gets the media artist */
+  /**
+   * Gets the media artist
+   *
+   * Returns the first of the media artists
+   */
   get artist(): string
-  /** Sets the media artist */
+  /**
+   * Sets the media artist
+   *
+   * Replaces all of the media artists with the provided artist
+   */
   set artist(artist: string)
+  /** Gets the media artists */
+  get artists(): Array<string>
+  /** Sets the media artists */
+  set artists(artists: Array<string>)
+  /** Gets the media album artists */
+  get albumArtists(): Array<string>
+  /** Sets the media album artists */
+  set albumArtists(albumArtists: Array<string>)
   /** Gets the media album title */
   get albumTitle(): string
   /** Sets the media artist */
   set albumTitle(albumTitle: string)
+  /** Gets the media genres */
+  get genres(): Array<string>
+  /** Sets the media genres */
+  set genres(genres: Array<string>)
+  /** Gets the media composers */
+  get composers(): Array<string>
+  /** Sets the media composers */
+  set composers(composers: Array<string>)
+  /** Gets the media lyricists */
+  get lyricists(): Array<string>
+  /** Sets the media lyricists */
+  set lyricists(lyricists: Array<string>)
+  /** Gets the media comments */
+  get comments(): Array<string>
+  /** Sets the media comments */
+  set comments(comments: Array<string>)
+  /** Gets the media track number */
+  get trackNumber(): number | null
+  /** Sets the media track number */
+  set trackNumber(trackNumber?: number | undefined | null)
+  /** Gets the media disc number */
+  get discNumber(): number | null
+  /** Sets the media disc number */
+  set discNumber(discNumber?: number | undefined | null)
+  /** Gets the media user rating */
+  get userRating(): number | null
+  /**
+   * Sets the media user rating
+   *
+   * The user rating must be between 0.0 and 1.0
+   */
+  set userRating(userRating?: number | undefined | null)
+  /** Gets the media use count */
+  get useCount(): number | null
+  /** Sets the media use count */
+  set useCount(useCount?: number | undefined | null)
+  /** Gets the media url */
+  get url(): string
+  /** Sets the media url */
+  set url(url: string)
+  /** Gets the media content created date */
+  get contentCreated(): number | null
+  /**
+   * Sets the media content created date
+   *
+   * The date is in milliseconds since the unix epoch
+   */
+  set contentCreated(contentCreated?: number | undefined | null)
+  /** Gets the media first used date */
+  get firstUsed(): number | null
+  /**
+   * Sets the media first used date
+   *
+   * The date is in milliseconds since the unix epoch
+   */
+  set firstUsed(firstUsed?: number | undefined | null)
+  /** Gets the media last used date */
+  get lastUsed(): number | null
+  /**
+   * Sets the media last used date
+   *
+   * The date is in milliseconds since the unix epoch
+   */
+  set lastUsed(lastUsed?: number | undefined | null)
+  /**
+   * Sets a custom metadata entry which is published alongside the rest of the metadata
+   *
+   * The key must be in the form of namespace:name, keys in the mpris namespace and xesam keys set through properties such as xesam:artist are reserved
+   *
+   * Integral numbers are published as 64-bit integers, other numbers as doubles and dates as ISO 8601 strings
+   */
+  setCustomMetadata(key: string, value: string | number | boolean | Array<string> | Date): void
+  /** Removes a custom metadata entry */
+  removeCustomMetadata(key: string): void
   /** Gets the track id */
   get trackId(): string
   /** Sets the track id */
   set trackId(trackId: string)
+  /** Gets the track list */
+  get trackList(): Array<MediaPlayerTrack>
+  /**
+   * Replaces the track list with the provided tracks
+   *
+   * The current track id should be the id of the track currently playing, if any, and must be in the provided tracks
+   */
+  setTrackList(tracks: Array<MediaPlayerTrack>, currentTrackId?: string | undefined | null): void
+  /**
+   * Adds a track to the track list
+   *
+   * The track is inserted after the track with the provided id or at the start of the track list when no id is provided
+   */
+  addTrack(track: MediaPlayerTrack, afterTrackId?: string | undefined | null): void
+  /** Removes a track from the track list */
+  removeTrack(trackId: string): void
+  /** Updates the metadata of a track in the track list */
+  updateTrack(track: MediaPlayerTrack): void
+  /** Gets the track list editable state */
+  get canEditTracks(): boolean
+  /**
+   * Sets the track list editable state
+   *
+   * When disabled the media service cannot request tracks to be added or removed
+   */
+  set canEditTracks(enabled: boolean)
+  /** Gets the playlists */
+  get playlists(): Array<MediaPlayerPlaylist>
+  /**
+   * Replaces the playlists with the provided playlists
+   *
+   * The order of the provided playlists is used as the user defined ordering
+   */
+  setPlaylists(playlists: Array<MediaPlayerPlaylist>): void
+  /**
+   * Updates a playlist
+   *
+   * The media service is notified when the name or icon of the playlist changes
+   */
+  updatePlaylist(playlist: MediaPlayerPlaylist): void
+  /** Gets the active playlist id */
+  get activePlaylistId(): string | null
+  /**
+   * Sets the active playlist id
+   *
+   * The playlist must be in the playlists, an empty value means no playlist is active
+   */
+  set activePlaylistId(playlistId: string | undefined | null)
+  /** Gets the supported playlist orderings */
+  get playlistOrderings(): Array<MediaPlayerPlaylistOrdering>
+  /**
+   * Sets the supported playlist orderings
+   *
+   * At least one ordering must be supported
+   */
+  set playlistOrderings(playlistOrderings: Array<MediaPlayerPlaylistOrdering>)
 }
@@ -295,10 +295,16 @@ if (!nativeBinding) {
   throw new Error(`Failed to load native binding`)
 }
 
-const { MediaPlayerThumbnailType, MediaPlayerMediaType, MediaPlayerPlaybackStatus, MediaPlayerThumbnail, MediaPlayer } = nativeBinding
+const { MediaPlayerThumbnailType, MediaPlayerMediaType, MediaPlayerPlaybackStatus, MediaPlayerRepeatMode, MediaPlayerPlaylistOrdering, MediaPlayerConnectionState, MediaPlayerBusType, MediaPlayerBackend, XosmsErrorCode, MediaPlayerThumbnail, MediaPlayer } = nativeBinding
 
 module.exports.MediaPlayerThumbnailType = MediaPlayerThumbnailType
 module.exports.MediaPlayerMediaType = MediaPlayerMediaType
 module.exports.MediaPlayerPlaybackStatus = MediaPlayerPlaybackStatus
+module.exports.MediaPlayerRepeatMode = MediaPlayerRepeatMode
+module.exports.MediaPlayerPlaylistOrdering = MediaPlayerPlaylistOrdering
+module.exports.MediaPlayerConnectionState = MediaPlayerConnectionState
+module.exports.MediaPlayerBusType = MediaPlayerBusType
+module.exports.MediaPlayerBackend = MediaPlayerBackend
+module.exports.XosmsErrorCode = XosmsErrorCode
 module.exports.MediaPlayerThumbnail = MediaPlayerThumbnail
 module.exports.MediaPlayer = MediaPlayer
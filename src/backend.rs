@@ -0,0 +1,48 @@
+use crate::error::{XosmsError, XosmsErrorCode};
+
+/// Environment variable selecting the backend when it is not passed to the constructor, either `native` or `mock`
+const BACKEND_VARIABLE: &str = "XOSMS_BACKEND";
+
+#[napi]
+#[derive(Debug, PartialEq, Eq)]
+pub enum MediaPlayerBackend {
+  /// Media services of the operating system
+  Native = 1,
+  /// Keeps the state in memory without the operating system seeing it, driven by the simulate functions
+  Mock = 2,
+}
+
+/// Resolves the backend passed to the constructor, falling back to the XOSMS_BACKEND environment variable
+pub fn resolve_backend(
+  backend: Option<MediaPlayerBackend>,
+) -> Result<MediaPlayerBackend, XosmsError> {
+  if let Some(backend) = backend {
+    return Ok(backend);
+  }
+
+  let Ok(value) = std::env::var(BACKEND_VARIABLE) else {
+    return Ok(MediaPlayerBackend::Native);
+  };
+  match value.to_lowercase().as_str() {
+    "" | "native" => Ok(MediaPlayerBackend::Native),
+    "mock" => Ok(MediaPlayerBackend::Mock),
+    _ => Err(
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+        format!(
+          "{} is not a valid {}, only native and mock may be used",
+          value, BACKEND_VARIABLE
+        ),
+      )
+      .detail("value", value),
+    ),
+  }
+}
+
+/// Error for the test hooks when they are used without the mock backend
+pub fn mock_only(function: &str) -> XosmsError {
+  XosmsError::new(
+    XosmsErrorCode::XOSMS_INVALID_STATE,
+    format!("{} is only available with the mock backend", function),
+  )
+}
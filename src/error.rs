@@ -0,0 +1,142 @@
+use std::cell::Cell;
+
+use napi::{Env, JsObject, JsUnknown, Status};
+
+thread_local! {
+  /// Environment of the JS thread, used to create errors with a code and details
+  static ENV: Cell<Option<Env>> = const { Cell::new(None) };
+}
+
+/// Stable codes set as the `code` of the errors thrown by xosms
+#[napi(string_enum)]
+#[derive(Debug, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum XosmsErrorCode {
+  /// The service name is owned by another process
+  XOSMS_NAME_TAKEN,
+  /// The bus could not be connected to
+  XOSMS_BUS_UNAVAILABLE,
+  /// The timeline, position or duration are inconsistent
+  XOSMS_INVALID_TIMELINE,
+  /// An enum value which can not be used, such as Unknown
+  XOSMS_INVALID_ENUM,
+  /// Waiting for the session bus took longer than the timeout
+  XOSMS_TIMEOUT,
+  /// An argument is out of range or malformed
+  XOSMS_INVALID_ARGUMENT,
+  /// A track or playlist id is not known
+  XOSMS_NOT_FOUND,
+  /// A track or playlist id is used more than once
+  XOSMS_DUPLICATE_ID,
+  /// The media player is not in a state which allows the call
+  XOSMS_INVALID_STATE,
+  /// The media services of the operating system failed
+  XOSMS_PLATFORM_ERROR,
+}
+
+/// Value attached to the `details` of an error
+pub enum XosmsErrorDetail {
+  String(String),
+  Number(f64),
+  Boolean(bool),
+}
+
+impl From<&str> for XosmsErrorDetail {
+  fn from(value: &str) -> Self {
+    XosmsErrorDetail::String(value.to_string())
+  }
+}
+
+impl From<String> for XosmsErrorDetail {
+  fn from(value: String) -> Self {
+    XosmsErrorDetail::String(value)
+  }
+}
+
+impl From<f64> for XosmsErrorDetail {
+  fn from(value: f64) -> Self {
+    XosmsErrorDetail::Number(value)
+  }
+}
+
+impl From<i32> for XosmsErrorDetail {
+  fn from(value: i32) -> Self {
+    XosmsErrorDetail::Number(value.into())
+  }
+}
+
+impl From<u32> for XosmsErrorDetail {
+  fn from(value: u32) -> Self {
+    XosmsErrorDetail::Number(value.into())
+  }
+}
+
+impl From<bool> for XosmsErrorDetail {
+  fn from(value: bool) -> Self {
+    XosmsErrorDetail::Boolean(value)
+  }
+}
+
+/// Error thrown to JS as an `Error` with a `code` and a `details` object, such as the offending value
+pub struct XosmsError {
+  code: XosmsErrorCode,
+  message: String,
+  details: Vec<(&'static str, XosmsErrorDetail)>,
+}
+
+impl XosmsError {
+  pub fn new<T: Into<String>>(code: XosmsErrorCode, message: T) -> Self {
+    Self {
+      code,
+      message: message.into(),
+      details: vec![],
+    }
+  }
+
+  /// Attaches a detail about the failure, such as the offending value
+  pub fn detail<T: Into<XosmsErrorDetail>>(mut self, key: &'static str, value: T) -> Self {
+    self.details.push((key, value.into()));
+    self
+  }
+
+  fn to_js(&self, env: &Env) -> napi::Result<JsObject> {
+    let mut error = env.create_error(napi::Error::new(
+      Status::GenericFailure,
+      self.message.to_owned(),
+    ))?;
+    error.set_named_property("code", env.create_string(&format!("{:?}", self.code))?)?;
+
+    let mut details = env.create_object()?;
+    for (key, value) in self.details.iter() {
+      let value: JsUnknown = match value {
+        XosmsErrorDetail::String(value) => env.create_string(value)?.into_unknown(),
+        XosmsErrorDetail::Number(value) => env.create_double(*value)?.into_unknown(),
+        XosmsErrorDetail::Boolean(value) => env.get_boolean(*value)?.into_unknown(),
+      };
+      details.set_named_property(key, value)?;
+    }
+    error.set_named_property("details", details)?;
+
+    Ok(error)
+  }
+}
+
+impl From<XosmsError> for napi::Error {
+  /// Creates the JS error right away, so this must happen on the JS thread to keep the code and details
+  fn from(error: XosmsError) -> Self {
+    let js_error = ENV
+      .with(|env| env.get())
+      .and_then(|env| error.to_js(&env).ok());
+    match js_error {
+      Some(js_error) => napi::Error::from(js_error.into_unknown()),
+      None => napi::Error::from_reason(error.message),
+    }
+  }
+}
+
+/// Remembers the environment of the calling JS thread
+///
+/// Called by the MediaPlayer constructors, as every other call which can fail needs a media player first.
+pub fn register_env(env: Env) {
+  ENV.with(|cell| cell.set(Some(env)));
+}
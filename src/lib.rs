@@ -3,6 +3,9 @@
 #[macro_use]
 extern crate napi_derive;
 
+mod backend;
+mod error;
+
 #[cfg(
   any(
     all(target_os = "windows", target_arch = "x86_64"), 
@@ -0,0 +1,67 @@
+use std::{sync::RwLock, time::Instant};
+
+/// A monotonic source of time used to extrapolate the playback position
+pub trait PlaybackClock: Send + Sync {
+  /// Returns the number of seconds elapsed since an arbitrary but fixed point in time
+  fn now(&self) -> f64;
+}
+
+/// Clock backed by the monotonic system clock
+pub struct SystemPlaybackClock {
+  start: Instant,
+}
+
+impl SystemPlaybackClock {
+  pub fn new() -> Self {
+    Self {
+      start: Instant::now(),
+    }
+  }
+}
+
+impl Default for SystemPlaybackClock {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl PlaybackClock for SystemPlaybackClock {
+  fn now(&self) -> f64 {
+    self.start.elapsed().as_secs_f64()
+  }
+}
+
+/// Clock which only moves when it is advanced, used for deterministic tests
+pub struct ManualPlaybackClock {
+  time: RwLock<f64>,
+}
+
+impl ManualPlaybackClock {
+  pub fn new() -> Self {
+    Self {
+      time: RwLock::new(0.0),
+    }
+  }
+
+  pub fn advance(&self, seconds: f64) {
+    if let Ok(mut time) = self.time.write() {
+      *time += seconds;
+    }
+  }
+}
+
+impl Default for ManualPlaybackClock {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl PlaybackClock for ManualPlaybackClock {
+  fn now(&self) -> f64 {
+    if let Ok(time) = self.time.read() {
+      return *time;
+    }
+
+    0.0
+  }
+}
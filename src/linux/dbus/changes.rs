@@ -0,0 +1,224 @@
+use std::{
+  collections::HashMap,
+  sync::{Arc, Mutex},
+  time::{Duration, Instant},
+};
+
+use super::waker::Waker;
+use dbus::{
+  arg::{ArgType, RefArg, Variant},
+  blocking::stdintf::org_freedesktop_dbus::{EmitsChangedSignal, PropertiesPropertiesChanged},
+  message::SignalArgs,
+  Message, Path,
+};
+
+/// Accumulates the changed properties of an interface until they are flushed
+///
+/// Clones share the accumulated changes so they can be flushed from the D-Bus thread. Properties whose values equal the last published values are dropped when flushing.
+#[derive(Clone)]
+pub struct PropertiesChangedQueue {
+  state: Arc<Mutex<PropertiesChangedQueueState>>,
+  waker: Waker,
+}
+
+struct PropertiesChangedQueueState {
+  properties_changed: PropertiesPropertiesChanged,
+  changed_at: Option<Instant>,
+  published: HashMap<String, Box<dyn RefArg>>,
+}
+
+impl PropertiesChangedQueue {
+  pub fn new(interface_name: &str, waker: Waker) -> Self {
+    Self {
+      state: Arc::new(Mutex::new(PropertiesChangedQueueState {
+        properties_changed: PropertiesPropertiesChanged {
+          interface_name: interface_name.to_string(),
+          changed_properties: Default::default(),
+          invalidated_properties: vec![],
+        },
+        changed_at: None,
+        published: HashMap::new(),
+      })),
+      waker,
+    }
+  }
+
+  pub fn add_prop<F: FnOnce() -> Box<dyn RefArg>>(
+    &self,
+    prop_name: &str,
+    emits: EmitsChangedSignal,
+    f: F,
+  ) -> bool {
+    if let Ok(mut state) = self.state.lock() {
+      let added = state.properties_changed.add_prop(prop_name, emits, f);
+      if added {
+        self.mark_changed(&mut state);
+      }
+      return added;
+    }
+
+    false
+  }
+
+  pub fn invalidate(&self, prop_name: &str) {
+    if let Ok(mut state) = self.state.lock() {
+      if !state
+        .properties_changed
+        .invalidated_properties
+        .iter()
+        .any(|p| p == prop_name)
+      {
+        state
+          .properties_changed
+          .invalidated_properties
+          .push(prop_name.to_string());
+      }
+      self.mark_changed(&mut state);
+    }
+  }
+
+  /// Queues every published property again so the next flush re-broadcasts them, such as after reconnecting to the bus
+  ///
+  /// Changes which are already queued take precedence over the published values.
+  pub fn republish(&self) {
+    if let Ok(mut state) = self.state.lock() {
+      let state = &mut *state;
+      for (name, value) in state.published.drain() {
+        state
+          .properties_changed
+          .changed_properties
+          .entry(name)
+          .or_insert(Variant(value));
+      }
+      if !state.properties_changed.changed_properties.is_empty() {
+        self.mark_changed(state);
+      }
+    }
+  }
+
+  fn mark_changed(&self, state: &mut PropertiesChangedQueueState) {
+    // The D-Bus thread needs to reschedule its auto flush when the first change is queued
+    if state.changed_at.is_none() {
+      state.changed_at = Some(Instant::now());
+      self.waker.wake();
+    }
+  }
+
+  /// Returns when the first change since the last flush was queued
+  pub fn changed_at(&self) -> Option<Instant> {
+    if let Ok(state) = self.state.lock() {
+      return state.changed_at;
+    }
+
+    None
+  }
+
+  /// Takes the accumulated changes as a PropertiesChanged signal, if any of them differ from the last published values
+  pub fn take_message(&self) -> Option<Message> {
+    if let Ok(mut state) = self.state.lock() {
+      let state = &mut *state;
+      state.changed_at = None;
+      state
+        .properties_changed
+        .changed_properties
+        .retain(|name, value| {
+          !state
+            .published
+            .get(name)
+            .is_some_and(|published| ref_arg_eq(&**published, &*value.0))
+        });
+      for (name, value) in state.properties_changed.changed_properties.iter() {
+        state.published.insert(name.to_owned(), value.0.box_clone());
+      }
+      for name in state.properties_changed.invalidated_properties.iter() {
+        state.published.remove(name);
+      }
+
+      if state.properties_changed.changed_properties.is_empty()
+        && state.properties_changed.invalidated_properties.is_empty()
+      {
+        return None;
+      }
+
+      let message = state
+        .properties_changed
+        .to_emit_message(&Path::new("/org/mpris/MediaPlayer2").unwrap());
+      state.properties_changed.changed_properties.clear();
+      state.properties_changed.invalidated_properties.clear();
+      return Some(message);
+    }
+
+    None
+  }
+}
+
+/// Flushes queued changes once the debounce window after the first queued change has passed
+pub struct AutoFlush {
+  queues: Vec<PropertiesChangedQueue>,
+  debounce: Duration,
+}
+
+impl AutoFlush {
+  pub fn new(queues: Vec<PropertiesChangedQueue>, debounce: Duration) -> Self {
+    Self { queues, debounce }
+  }
+
+  /// Returns when the queued changes are due to be flushed
+  pub fn deadline(&self) -> Option<Instant> {
+    self
+      .queues
+      .iter()
+      .filter_map(|queue| queue.changed_at())
+      .min()
+      .map(|changed_at| changed_at + self.debounce)
+  }
+
+  pub fn flush(&self) -> Vec<Message> {
+    self
+      .queues
+      .iter()
+      .filter_map(|queue| queue.take_message())
+      .collect()
+  }
+}
+
+/// Compares two D-Bus values, ignoring the order of dictionary entries
+fn ref_arg_eq(a: &dyn RefArg, b: &dyn RefArg) -> bool {
+  if a.signature() != b.signature() {
+    return false;
+  }
+
+  match a.arg_type() {
+    ArgType::Array | ArgType::Struct | ArgType::Variant | ArgType::DictEntry => {
+      let (Some(a_iter), Some(b_iter)) = (a.as_iter(), b.as_iter()) else {
+        return false;
+      };
+      let a_items: Vec<&dyn RefArg> = a_iter.collect();
+      let b_items: Vec<&dyn RefArg> = b_iter.collect();
+      if a_items.len() != b_items.len() {
+        return false;
+      }
+
+      if a.signature().starts_with("a{") {
+        // Dictionaries iterate as alternating keys and values in no particular order
+        let b_entries: Vec<_> = b_items.chunks(2).collect();
+        a_items.chunks(2).all(|a_entry| {
+          b_entries
+            .iter()
+            .any(|b_entry| ref_arg_eq(a_entry[0], b_entry[0]) && ref_arg_eq(a_entry[1], b_entry[1]))
+        })
+      } else {
+        a_items
+          .iter()
+          .zip(b_items.iter())
+          .all(|(a, b)| ref_arg_eq(*a, *b))
+      }
+    }
+    _ => {
+      a.as_i64() == b.as_i64()
+        && a.as_u64() == b.as_u64()
+        && a.as_f64() == b.as_f64()
+        && a.as_str() == b.as_str()
+    }
+  }
+}
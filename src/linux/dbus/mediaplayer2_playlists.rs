@@ -0,0 +1,61 @@
+// This code was autogenerated with `dbus-codegen-rust --crossroads`, see https://github.com/diwic/dbus-rs
+use dbus as dbus;
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus_crossroads as crossroads;
+
+pub trait OrgMprisMediaPlayer2Playlists {
+    fn activate_playlist(&mut self, playlist_id: dbus::Path<'static>) -> Result<(), dbus::MethodErr>;
+    fn get_playlists(&mut self, index: u32, max_count: u32, order: String, reverse_order: bool) -> Result<Vec<(dbus::Path<'static>, String, String)>, dbus::MethodErr>;
+    fn playlist_count(&self) -> Result<u32, dbus::MethodErr>;
+    fn orderings(&self) -> Result<Vec<String>, dbus::MethodErr>;
+    fn active_playlist(&self) -> Result<(bool, (dbus::Path<'static>, String, String)), dbus::MethodErr>;
+}
+
+#[derive(Debug)]
+pub struct OrgMprisMediaPlayer2PlaylistsPlaylistChanged {
+    pub playlist: (dbus::Path<'static>, String, String),
+}
+
+impl arg::AppendAll for OrgMprisMediaPlayer2PlaylistsPlaylistChanged {
+    fn append(&self, i: &mut arg::IterAppend) {
+        arg::RefArg::append(&self.playlist, i);
+    }
+}
+
+impl arg::ReadAll for OrgMprisMediaPlayer2PlaylistsPlaylistChanged {
+    fn read(i: &mut arg::Iter) -> Result<Self, arg::TypeMismatchError> {
+        Ok(OrgMprisMediaPlayer2PlaylistsPlaylistChanged {
+            playlist: i.read()?,
+        })
+    }
+}
+
+impl dbus::message::SignalArgs for OrgMprisMediaPlayer2PlaylistsPlaylistChanged {
+    const NAME: &'static str = "PlaylistChanged";
+    const INTERFACE: &'static str = "org.mpris.MediaPlayer2.Playlists";
+}
+
+pub fn register_org_mpris_media_player2_playlists<T>(cr: &mut crossroads::Crossroads) -> crossroads::IfaceToken<T>
+where T: OrgMprisMediaPlayer2Playlists + Send + 'static
+{
+    cr.register("org.mpris.MediaPlayer2.Playlists", |b| {
+        b.signal::<((dbus::Path<'static>, String, String),), _>("PlaylistChanged", ("Playlist",));
+        b.method("ActivatePlaylist", ("PlaylistId",), (), |_, t: &mut T, (playlist_id,)| {
+            t.activate_playlist(playlist_id,)
+        });
+        b.method("GetPlaylists", ("Index","MaxCount","Order","ReverseOrder",), ("Playlists",), |_, t: &mut T, (index,max_count,order,reverse_order,)| {
+            t.get_playlists(index,max_count,order,reverse_order,)
+                .map(|x| (x,))
+        });
+        b.property::<u32, _>("PlaylistCount")
+            .get(|_, t| t.playlist_count())
+            .annotate("org.freedesktop.DBus.Property.EmitsChangedSignal", "true");
+        b.property::<Vec<String>, _>("Orderings")
+            .get(|_, t| t.orderings())
+            .annotate("org.freedesktop.DBus.Property.EmitsChangedSignal", "true");
+        b.property::<(bool, (dbus::Path<'static>, String, String)), _>("ActivePlaylist")
+            .get(|_, t| t.active_playlist())
+            .annotate("org.freedesktop.DBus.Property.EmitsChangedSignal", "true");
+    })
+}
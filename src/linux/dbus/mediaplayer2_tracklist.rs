@@ -0,0 +1,149 @@
+// This code was autogenerated with `dbus-codegen-rust --crossroads`, see https://github.com/diwic/dbus-rs
+use dbus as dbus;
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus_crossroads as crossroads;
+
+pub trait OrgMprisMediaPlayer2TrackList {
+    fn get_tracks_metadata(&mut self, track_ids: Vec<dbus::Path<'static>>) -> Result<Vec<arg::PropMap>, dbus::MethodErr>;
+    fn add_track(&mut self, uri: String, after_track: dbus::Path<'static>, set_as_current: bool) -> Result<(), dbus::MethodErr>;
+    fn remove_track(&mut self, track_id: dbus::Path<'static>) -> Result<(), dbus::MethodErr>;
+    fn go_to(&mut self, track_id: dbus::Path<'static>) -> Result<(), dbus::MethodErr>;
+    fn tracks(&self) -> Result<Vec<dbus::Path<'static>>, dbus::MethodErr>;
+    fn can_edit_tracks(&self) -> Result<bool, dbus::MethodErr>;
+}
+
+#[derive(Debug)]
+pub struct OrgMprisMediaPlayer2TrackListTrackListReplaced {
+    pub tracks: Vec<dbus::Path<'static>>,
+    pub current_track: dbus::Path<'static>,
+}
+
+impl arg::AppendAll for OrgMprisMediaPlayer2TrackListTrackListReplaced {
+    fn append(&self, i: &mut arg::IterAppend) {
+        arg::RefArg::append(&self.tracks, i);
+        arg::RefArg::append(&self.current_track, i);
+    }
+}
+
+impl arg::ReadAll for OrgMprisMediaPlayer2TrackListTrackListReplaced {
+    fn read(i: &mut arg::Iter) -> Result<Self, arg::TypeMismatchError> {
+        Ok(OrgMprisMediaPlayer2TrackListTrackListReplaced {
+            tracks: i.read()?,
+            current_track: i.read()?,
+        })
+    }
+}
+
+impl dbus::message::SignalArgs for OrgMprisMediaPlayer2TrackListTrackListReplaced {
+    const NAME: &'static str = "TrackListReplaced";
+    const INTERFACE: &'static str = "org.mpris.MediaPlayer2.TrackList";
+}
+
+#[derive(Debug)]
+pub struct OrgMprisMediaPlayer2TrackListTrackAdded {
+    pub metadata: arg::PropMap,
+    pub after_track: dbus::Path<'static>,
+}
+
+impl arg::AppendAll for OrgMprisMediaPlayer2TrackListTrackAdded {
+    fn append(&self, i: &mut arg::IterAppend) {
+        arg::RefArg::append(&self.metadata, i);
+        arg::RefArg::append(&self.after_track, i);
+    }
+}
+
+impl arg::ReadAll for OrgMprisMediaPlayer2TrackListTrackAdded {
+    fn read(i: &mut arg::Iter) -> Result<Self, arg::TypeMismatchError> {
+        Ok(OrgMprisMediaPlayer2TrackListTrackAdded {
+            metadata: i.read()?,
+            after_track: i.read()?,
+        })
+    }
+}
+
+impl dbus::message::SignalArgs for OrgMprisMediaPlayer2TrackListTrackAdded {
+    const NAME: &'static str = "TrackAdded";
+    const INTERFACE: &'static str = "org.mpris.MediaPlayer2.TrackList";
+}
+
+#[derive(Debug)]
+pub struct OrgMprisMediaPlayer2TrackListTrackRemoved {
+    pub track_id: dbus::Path<'static>,
+}
+
+impl arg::AppendAll for OrgMprisMediaPlayer2TrackListTrackRemoved {
+    fn append(&self, i: &mut arg::IterAppend) {
+        arg::RefArg::append(&self.track_id, i);
+    }
+}
+
+impl arg::ReadAll for OrgMprisMediaPlayer2TrackListTrackRemoved {
+    fn read(i: &mut arg::Iter) -> Result<Self, arg::TypeMismatchError> {
+        Ok(OrgMprisMediaPlayer2TrackListTrackRemoved {
+            track_id: i.read()?,
+        })
+    }
+}
+
+impl dbus::message::SignalArgs for OrgMprisMediaPlayer2TrackListTrackRemoved {
+    const NAME: &'static str = "TrackRemoved";
+    const INTERFACE: &'static str = "org.mpris.MediaPlayer2.TrackList";
+}
+
+#[derive(Debug)]
+pub struct OrgMprisMediaPlayer2TrackListTrackMetadataChanged {
+    pub track_id: dbus::Path<'static>,
+    pub metadata: arg::PropMap,
+}
+
+impl arg::AppendAll for OrgMprisMediaPlayer2TrackListTrackMetadataChanged {
+    fn append(&self, i: &mut arg::IterAppend) {
+        arg::RefArg::append(&self.track_id, i);
+        arg::RefArg::append(&self.metadata, i);
+    }
+}
+
+impl arg::ReadAll for OrgMprisMediaPlayer2TrackListTrackMetadataChanged {
+    fn read(i: &mut arg::Iter) -> Result<Self, arg::TypeMismatchError> {
+        Ok(OrgMprisMediaPlayer2TrackListTrackMetadataChanged {
+            track_id: i.read()?,
+            metadata: i.read()?,
+        })
+    }
+}
+
+impl dbus::message::SignalArgs for OrgMprisMediaPlayer2TrackListTrackMetadataChanged {
+    const NAME: &'static str = "TrackMetadataChanged";
+    const INTERFACE: &'static str = "org.mpris.MediaPlayer2.TrackList";
+}
+
+pub fn register_org_mpris_media_player2_track_list<T>(cr: &mut crossroads::Crossroads) -> crossroads::IfaceToken<T>
+where T: OrgMprisMediaPlayer2TrackList + Send + 'static
+{
+    cr.register("org.mpris.MediaPlayer2.TrackList", |b| {
+        b.signal::<(Vec<dbus::Path<'static>>,dbus::Path<'static>,), _>("TrackListReplaced", ("Tracks","CurrentTrack",));
+        b.signal::<(arg::PropMap,dbus::Path<'static>,), _>("TrackAdded", ("Metadata","AfterTrack",));
+        b.signal::<(dbus::Path<'static>,), _>("TrackRemoved", ("TrackId",));
+        b.signal::<(dbus::Path<'static>,arg::PropMap,), _>("TrackMetadataChanged", ("TrackId","Metadata",));
+        b.method("GetTracksMetadata", ("TrackIds",), ("Metadata",), |_, t: &mut T, (track_ids,)| {
+            t.get_tracks_metadata(track_ids,)
+                .map(|x| (x,))
+        });
+        b.method("AddTrack", ("Uri","AfterTrack","SetAsCurrent",), (), |_, t: &mut T, (uri,after_track,set_as_current,)| {
+            t.add_track(uri,after_track,set_as_current,)
+        });
+        b.method("RemoveTrack", ("TrackId",), (), |_, t: &mut T, (track_id,)| {
+            t.remove_track(track_id,)
+        });
+        b.method("GoTo", ("TrackId",), (), |_, t: &mut T, (track_id,)| {
+            t.go_to(track_id,)
+        });
+        b.property::<Vec<dbus::Path<'static>>, _>("Tracks")
+            .get(|_, t| t.tracks())
+            .annotate("org.freedesktop.DBus.Property.EmitsChangedSignal", "invalidates");
+        b.property::<bool, _>("CanEditTracks")
+            .get(|_, t| t.can_edit_tracks())
+            .annotate("org.freedesktop.DBus.Property.EmitsChangedSignal", "true");
+    })
+}
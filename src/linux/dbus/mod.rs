@@ -1,3 +1,7 @@
+pub mod changes;
 pub mod mediaplayer2;
 pub mod mediaplayer2_player;
-pub mod session;
\ No newline at end of file
+pub mod mediaplayer2_playlists;
+pub mod mediaplayer2_tracklist;
+pub mod session;
+pub mod waker;
\ No newline at end of file
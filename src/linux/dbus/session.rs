@@ -1,7 +1,13 @@
 use std::{
-  sync::mpsc,
-  thread::{self, JoinHandle},
-  time::Duration,
+  collections::HashMap,
+  fmt,
+  sync::{
+    atomic::{AtomicUsize, Ordering},
+    mpsc::{self, TryRecvError},
+    Arc, Mutex, Weak,
+  },
+  thread,
+  time::{Duration, Instant},
 };
 
 use dbus::{
@@ -9,121 +15,713 @@ use dbus::{
     stdintf::org_freedesktop_dbus::{ReleaseNameReply, RequestNameReply},
     Connection,
   },
-  channel::Sender,
+  channel::{BusType, Channel, Sender},
+  message::MessageType,
   Message,
 };
 use dbus_crossroads::Crossroads;
 
+use super::{
+  changes::{AutoFlush, PropertiesChangedQueue},
+  waker::Waker,
+};
+
+const INITIAL_RECONNECT_DELAY: Duration = Duration::from_millis(500);
+const MAXIMUM_RECONNECT_DELAY: Duration = Duration::from_secs(30);
+
+static SHARED_SESSIONS: Mutex<Vec<(BusTarget, Weak<SharedSession>)>> = Mutex::new(Vec::new());
+
+enum DBusCommand {
+  Register(
+    usize,
+    MediaPlayerRegistration,
+    oneshot::Sender<Result<(String, bool), RegistrationError>>,
+  ),
+  Unregister(usize, oneshot::Sender<bool>),
+  EmitMessage(usize, Message),
+  SetAutoFlush(usize, Option<AutoFlush>),
+  Shutdown,
+}
+
+/// Where the media players are served
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BusTarget {
+  Session,
+  System,
+  /// Message bus listening on an address such as `unix:path=/run/user/1000/bus`
+  Address(String),
+  /// Peer-to-peer connection to an address, without a message bus to own names on
+  Private(String),
+  /// No bus at all, used by the mock backend so media players are only visible to each other
+  Mock,
+}
+
+impl fmt::Display for BusTarget {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      BusTarget::Session => write!(f, "session bus"),
+      BusTarget::System => write!(f, "system bus"),
+      BusTarget::Address(address) => write!(f, "bus at {}", address),
+      BusTarget::Private(address) => write!(f, "peer at {}", address),
+      BusTarget::Mock => write!(f, "mock backend"),
+    }
+  }
+}
+
+/// How a bus name is requested, mirroring the flags of RequestName
+#[derive(Clone, Copy)]
+pub struct NameOwnership {
+  /// Whether another connection may take over the name
+  pub allow_replacement: bool,
+  /// Whether to take over the name if its current owner allows replacement
+  pub replace_existing: bool,
+  /// Whether to wait in the queue for the name when it is taken instead of failing
+  pub queue: bool,
+  /// Whether to fall back to the name suffixed with `.instance<pid>` when the name is taken
+  pub instance_fallback: bool,
+}
+
+/// Why the bus name of a media player could not be requested
+#[derive(Debug)]
+pub enum RegistrationError {
+  /// The bus could not be connected to, with the reason reported by libdbus
+  BusUnavailable(String),
+  /// The name is owned by another connection and could not be taken over or queued for
+  NameTaken,
+}
+
+/// Changes to a registered media player reported from the D-Bus thread
+pub enum SessionEvent {
+  /// The connection to the bus was lost
+  Disconnected,
+  /// The connection to the bus was restored and the name requested again
+  Reconnected,
+  /// The bus name was acquired after queueing for it
+  NameAcquired(String),
+  /// The bus name was taken over by another connection
+  NameLost(String),
+}
+
+/// A media player to serve on the bus
+pub struct MediaPlayerRegistration {
+  /// Bus name to request
+  pub name: String,
+  pub ownership: NameOwnership,
+  pub crossroads: Crossroads,
+  /// Queues whose published state is re-broadcast after reconnecting
+  pub queues: Vec<PropertiesChangedQueue>,
+  /// Called from the D-Bus thread
+  pub on_event: Box<dyn Fn(SessionEvent) + Send>,
+}
+
+/// Handle to the D-Bus session shared by every media player in the process serving on the same bus
+///
+/// The session thread is started by the first registration and stopped once every handle has been dropped. Each registered media player is served on its own connection, as clients tell media players apart by the sender of their signals. When a connection is lost it is reopened with exponential backoff and the name of its media player is requested again.
 pub struct DBusSession {
-  dbus_connection_handle: JoinHandle<()>,
-  register_name: mpsc::Sender<(String, Crossroads, oneshot::Sender<bool>)>,
-  unregister_name: mpsc::Sender<(String, oneshot::Sender<bool>)>,
-  emit_message: mpsc::Sender<Message>,
+  session: Arc<SharedSession>,
+  player_id: usize,
+}
+
+struct SharedSession {
+  target: BusTarget,
+  commands: mpsc::Sender<DBusCommand>,
+  /// Receiving end of the commands until the D-Bus thread is started
+  pending_commands: Mutex<Option<mpsc::Receiver<DBusCommand>>>,
+  waker: Waker,
+  next_player_id: AtomicUsize,
 }
 
 impl DBusSession {
-  pub fn new() -> Self {
-    let (register_name_sender, register_name_receiver) =
-      mpsc::channel::<(String, Crossroads, oneshot::Sender<bool>)>();
-    let (unregister_name_sender, unregister_name_receiver) =
-      mpsc::channel::<(String, oneshot::Sender<bool>)>();
-    let (emit_message_sender, emit_message_receiver) = mpsc::channel::<Message>();
-
-    let dbus_connection_handle = thread::spawn(move || {
-      let mut media_player: Option<Crossroads> = None;
+  pub fn new(target: BusTarget) -> Self {
+    let mut shared_sessions = SHARED_SESSIONS
+      .lock()
+      .unwrap_or_else(|error| error.into_inner());
+    shared_sessions.retain(|(_, session)| session.strong_count() > 0);
+    let shared_session = shared_sessions
+      .iter()
+      .find(|(shared_target, _)| *shared_target == target)
+      .and_then(|(_, session)| session.upgrade());
+    let session = match shared_session {
+      Some(session) => session,
+      None => {
+        let session = Arc::new(SharedSession::new(target.clone()));
+        shared_sessions.push((target, Arc::downgrade(&session)));
+        session
+      }
+    };
+    let player_id = session.next_player_id.fetch_add(1, Ordering::Relaxed);
+
+    Self { session, player_id }
+  }
+
+  /// Requests the bus name of the media player and starts serving it
+  ///
+  /// Responds with the bus name which was acquired or queued for, which differs from the requested name when falling back to the instance name, and whether it is owned. The registration is undone if the response is no longer awaited once the D-Bus thread gets to it.
+  pub fn register(
+    &self,
+    registration: MediaPlayerRegistration,
+  ) -> oneshot::Receiver<Result<(String, bool), RegistrationError>> {
+    let (response_sender, response_receiver) = oneshot::channel();
+    self.session.send(DBusCommand::Register(
+      self.player_id,
+      registration,
+      response_sender,
+    ));
+    response_receiver
+  }
+
+  /// Releases the bus name of the media player and stops serving it
+  ///
+  /// Responds with whether the name was released. The response does not need to be awaited.
+  pub fn unregister(&self) -> oneshot::Receiver<bool> {
+    let (response_sender, response_receiver) = oneshot::channel();
+    self
+      .session
+      .send(DBusCommand::Unregister(self.player_id, response_sender));
+    response_receiver
+  }
+
+  /// Returns a handle undoing the registration of the media player, used when its response is no longer awaited
+  pub fn registration_canceller(&self) -> RegistrationCanceller {
+    RegistrationCanceller {
+      session: self.session.clone(),
+      player_id: self.player_id,
+    }
+  }
+
+  pub fn emit_message(&self, message: Message) {
+    self
+      .session
+      .send(DBusCommand::EmitMessage(self.player_id, message));
+  }
+
+  pub fn set_auto_flush(&self, auto_flush: Option<AutoFlush>) {
+    self
+      .session
+      .send(DBusCommand::SetAutoFlush(self.player_id, auto_flush));
+  }
+
+  pub fn target(&self) -> &BusTarget {
+    &self.session.target
+  }
+
+  /// Returns a waker for the D-Bus thread, used to reschedule auto flushing when changes are queued
+  pub fn waker(&self) -> Waker {
+    self.session.waker.clone()
+  }
+}
+
+/// Undoes a registration which is still waiting for the bus, without dropping the handle of the media player
+pub struct RegistrationCanceller {
+  session: Arc<SharedSession>,
+  player_id: usize,
+}
+
+impl RegistrationCanceller {
+  /// Releases the bus name once the registration has been processed, without awaiting the response
+  pub fn cancel(&self) {
+    let (response_sender, _) = oneshot::channel();
+    self
+      .session
+      .send(DBusCommand::Unregister(self.player_id, response_sender));
+  }
+}
+
+impl Drop for DBusSession {
+  fn drop(&mut self) {
+    self
+      .session
+      .send(DBusCommand::SetAutoFlush(self.player_id, None));
+  }
+}
+
+impl SharedSession {
+  fn new(target: BusTarget) -> Self {
+    let (command_sender, command_receiver) = mpsc::channel::<DBusCommand>();
+    let waker = Waker::new().expect("Failed to create the D-Bus thread waker");
+
+    Self {
+      target,
+      commands: command_sender,
+      pending_commands: Mutex::new(Some(command_receiver)),
+      waker,
+      next_player_id: AtomicUsize::new(0),
+    }
+  }
+
+  fn send(&self, command: DBusCommand) {
+    let mut pending_commands = self
+      .pending_commands
+      .lock()
+      .unwrap_or_else(|error| error.into_inner());
+    if pending_commands.is_some() {
+      match command {
+        DBusCommand::Register(..) => {
+          if let Some(command_receiver) = pending_commands.take() {
+            self.start(command_receiver);
+          }
+        }
+        // Nothing is served before the first registration so there is nothing to unregister or emit to
+        DBusCommand::Unregister(_, response) => {
+          let _ = response.send(false);
+          return;
+        }
+        DBusCommand::EmitMessage(..) | DBusCommand::Shutdown => return,
+        // Kept in the channel until the D-Bus thread is started
+        DBusCommand::SetAutoFlush(..) => {}
+      }
+    }
+    drop(pending_commands);
+
+    let _ = self.commands.send(command);
+    self.waker.wake();
+  }
+
+  /// Starts the D-Bus thread, which stops by itself once the shutdown command is sent or every sender is dropped
+  fn start(&self, command_receiver: mpsc::Receiver<DBusCommand>) {
+    let waker = self.waker.clone();
+    let target = self.target.clone();
+
+    thread::spawn(move || {
+      SessionThread {
+        target,
+        media_players: HashMap::new(),
+        auto_flushes: HashMap::new(),
+        reconnect_at: None,
+        reconnect_delay: INITIAL_RECONNECT_DELAY,
+      }
+      .run(command_receiver, waker)
+    });
+  }
+}
+
+impl Drop for SharedSession {
+  fn drop(&mut self) {
+    self.send(DBusCommand::Shutdown);
+  }
+}
+
+struct RegisteredMediaPlayer {
+  registration: MediaPlayerRegistration,
+  /// Connection serving only this media player, so clients can tell the signals of media players apart by their sender
+  connection: Option<Connection>,
+  /// Bus name which was acquired or queued for on the current connection
+  name: String,
+  /// Whether the bus name is currently owned rather than queued for or lost
+  owns_name: bool,
+  connected: bool,
+}
+
+/// State owned by the D-Bus thread
+struct SessionThread {
+  target: BusTarget,
+  media_players: HashMap<usize, RegisteredMediaPlayer>,
+  auto_flushes: HashMap<usize, AutoFlush>,
+  reconnect_at: Option<Instant>,
+  reconnect_delay: Duration,
+}
+
+impl SessionThread {
+  fn run(mut self, commands: mpsc::Receiver<DBusCommand>, waker: Waker) {
+    loop {
+      waker.drain();
       loop {
-        let connection_result = Connection::new_session();
-        if let Ok(connection) = connection_result {
-          loop {
-            match register_name_receiver.try_recv() {
-              Ok((name, crossroads, response)) => {
-                if let Ok(request_name_reply) = connection.request_name(&name, false, true, true) {
-                  if request_name_reply == RequestNameReply::PrimaryOwner {
-                    media_player = Some(crossroads);
-                    let _ = response.send(true);
-                  } else {
-                    let _ = response.send(false);
-                  }
-                } else {
-                  let _ = response.send(false);
-                }
-                // }
-              }
-              _ => {}
-            }
-            match unregister_name_receiver.try_recv() {
-              Ok((name, response)) => {
-                if media_player.is_some() {
-                  media_player = None;
-                  if let Ok(release_name_reply) = connection.release_name(&name) {
-                    if release_name_reply == ReleaseNameReply::Released {
-                      let _ = response.send(true);
-                    } else {
-                      let _ = response.send(false);
-                    }
-                  } else {
-                    let _ = response.send(false);
-                  }
-                }
-              }
-              _ => {}
-            }
-            match emit_message_receiver.try_recv() {
-              Ok(message) => {
-                let _ = connection.send(message);
-              }
-              _ => {}
-            }
-            let _ = connection
-              .channel()
-              .read_write(Some(Duration::from_secs(0)));
-            loop {
-              if let Some(message) = connection.channel().pop_message() {
-                if let Some(crossroads) = media_player.as_mut() {
-                  let _ = crossroads.handle_message(message, &connection);
-                }
-              } else {
-                break;
-              }
+        match commands.try_recv() {
+          Ok(DBusCommand::Shutdown) | Err(TryRecvError::Disconnected) => return,
+          Ok(command) => self.handle_command(command),
+          Err(TryRecvError::Empty) => break,
+        }
+      }
+
+      if self
+        .reconnect_at
+        .is_some_and(|reconnect_at| reconnect_at <= Instant::now())
+      {
+        self.reconnect();
+      }
+
+      for (player_id, auto_flush) in self.auto_flushes.iter() {
+        if auto_flush
+          .deadline()
+          .is_some_and(|deadline| deadline <= Instant::now())
+        {
+          for message in auto_flush.flush() {
+            self.send(*player_id, message);
+          }
+        }
+      }
+
+      let mut fds = vec![(waker.fd(), libc::POLLIN)];
+      fds.extend(self.process_connections());
+
+      // Sleep until a connection has data, a command is sent or the next auto flush or reconnection attempt is due
+      let timeout = self
+        .auto_flushes
+        .values()
+        .filter_map(|auto_flush| auto_flush.deadline())
+        .chain(self.reconnect_at)
+        .min()
+        .map(|deadline| deadline.saturating_duration_since(Instant::now()));
+      wait(&fds, timeout);
+    }
+  }
+
+  fn handle_command(&mut self, command: DBusCommand) {
+    match command {
+      DBusCommand::Register(player_id, registration, response) => {
+        // Registering again replaces the previous registration, such as one which completed after its response timed out
+        self.release(player_id);
+        let connection = if self.target == BusTarget::Mock {
+          None
+        } else {
+          match connect(&self.target) {
+            Ok(connection) => Some(connection),
+            Err(reason) => {
+              let _ = response.send(Err(RegistrationError::BusUnavailable(reason)));
+              return;
             }
           }
+        };
+        let names_in_use = self.names_in_use();
+        let acquired = match connection.as_ref() {
+          Some(connection) => acquire_name(
+            &self.target,
+            connection,
+            &registration.name,
+            registration.ownership,
+            &names_in_use,
+          ),
+          // Without a bus the name is only taken by the other media players
+          None => (!names_in_use.contains(&registration.name))
+            .then(|| (registration.name.to_owned(), true)),
+        };
+        let Some((name, owns_name)) = acquired else {
+          let _ = response.send(Err(RegistrationError::NameTaken));
+          return;
+        };
+
+        // Nobody is waiting for the registration anymore, such as after timing out, so dropping the connection gives the name back
+        if response.send(Ok((name.to_owned(), owns_name))).is_err() {
+          return;
         }
+
+        self.media_players.insert(
+          player_id,
+          RegisteredMediaPlayer {
+            registration,
+            connection,
+            name,
+            owns_name,
+            connected: true,
+          },
+        );
       }
-    });
+      DBusCommand::Unregister(player_id, response) => {
+        let released = self.release(player_id);
+        let _ = response.send(released);
+      }
+      DBusCommand::EmitMessage(player_id, message) => self.send(player_id, message),
+      DBusCommand::SetAutoFlush(player_id, Some(auto_flush)) => {
+        self.auto_flushes.insert(player_id, auto_flush);
+      }
+      DBusCommand::SetAutoFlush(player_id, None) => {
+        self.auto_flushes.remove(&player_id);
+      }
+      DBusCommand::Shutdown => {}
+    }
+  }
 
-    Self {
-      dbus_connection_handle,
-      register_name: register_name_sender,
-      unregister_name: unregister_name_sender,
-      emit_message: emit_message_sender,
+  /// Releases the bus name of a media player and stops serving it, returning whether a name was released
+  fn release(&mut self, player_id: usize) -> bool {
+    let released = match self.media_players.remove(&player_id) {
+      Some(media_player) if media_player.connected => release_name(&self.target, &media_player),
+      Some(_) => true,
+      None => false,
+    };
+    if self
+      .media_players
+      .values()
+      .all(|media_player| media_player.connected)
+    {
+      self.reconnect_at = None;
     }
+    released
   }
 
-  pub fn register(&self, name: &String, crossroads: Crossroads) -> bool {
-    let name = format!("org.mpris.MediaPlayer2.{}", &name);
-    let (response_sender, response_receiver) = oneshot::channel();
-    let _ = self
-      .register_name
-      .send((name.to_owned(), crossroads, response_sender));
-    match response_receiver.recv_timeout(Duration::from_secs(1)) {
-      Ok(result) => result,
-      _ => false,
+  /// Reads and dispatches incoming messages, returning the connection file descriptors to wait on
+  fn process_connections(&mut self) -> Vec<(i32, i16)> {
+    let mut fds = vec![];
+    let mut lost = vec![];
+    for (player_id, media_player) in self.media_players.iter_mut() {
+      let RegisteredMediaPlayer {
+        registration,
+        connection: Some(connection),
+        name,
+        owns_name,
+        ..
+      } = media_player
+      else {
+        continue;
+      };
+      if connection
+        .channel()
+        .read_write(Some(Duration::from_secs(0)))
+        .is_err()
+      {
+        lost.push(*player_id);
+        continue;
+      }
+
+      // Every message on the connection is addressed to this media player, unknown objects and methods are answered with an error by crossroads
+      while let Some(message) = connection.channel().pop_message() {
+        if message.msg_type() == MessageType::Signal {
+          handle_name_signal(registration, name, owns_name, &message);
+          continue;
+        }
+
+        let _ = registration.crossroads.handle_message(message, connection);
+      }
+      connection.channel().flush();
+
+      let watch = connection.channel().watch();
+      let mut events = 0;
+      if watch.read {
+        events |= libc::POLLIN;
+      }
+      if watch.write {
+        events |= libc::POLLOUT;
+      }
+      fds.push((watch.fd, events));
     }
+
+    for player_id in lost {
+      self.disconnect(player_id);
+    }
+    fds
   }
 
-  pub fn unregister(&self, name: &String) -> bool {
-    let name = format!("org.mpris.MediaPlayer2.{}", &name);
-    let (response_sender, response_receiver) = oneshot::channel();
-    let _ = self
-      .unregister_name
-      .send((name.to_owned(), response_sender));
-    match response_receiver.recv_timeout(Duration::from_secs(1)) {
-      Ok(result) => result,
-      _ => false,
+  /// Drops the lost connection of a media player and schedules it to be registered again
+  fn disconnect(&mut self, player_id: usize) {
+    let Some(media_player) = self.media_players.get_mut(&player_id) else {
+      return;
+    };
+    media_player.connection = None;
+    if media_player.connected {
+      media_player.connected = false;
+      media_player.owns_name = false;
+      (media_player.registration.on_event)(SessionEvent::Disconnected);
+    }
+
+    if self.reconnect_at.is_none() {
+      self.reconnect_delay = INITIAL_RECONNECT_DELAY;
+      self.reconnect_at = Some(Instant::now() + self.reconnect_delay);
     }
   }
 
-  pub fn emit_message(&self, message: Message) {
-    let _ = self.emit_message.send(message);
+  /// Requests the names of the media players which lost them and re-broadcasts their state
+  ///
+  /// Failed attempts are retried with an exponentially growing delay.
+  fn reconnect(&mut self) {
+    let mut names_in_use = self.names_in_use();
+    for media_player in self.media_players.values_mut() {
+      if media_player.connected {
+        continue;
+      }
+      if media_player.connection.is_none() {
+        media_player.connection = connect(&self.target).ok();
+      }
+      let Some(connection) = media_player.connection.as_ref() else {
+        continue;
+      };
+      let Some((name, owns_name)) = acquire_name(
+        &self.target,
+        connection,
+        &media_player.registration.name,
+        media_player.registration.ownership,
+        &names_in_use,
+      ) else {
+        continue;
+      };
+
+      names_in_use.push(name.to_owned());
+      for queue in media_player.registration.queues.iter() {
+        queue.republish();
+        if let Some(message) = queue.take_message() {
+          let _ = connection.send(message);
+        }
+      }
+      media_player.name = name;
+      media_player.owns_name = owns_name;
+      media_player.connected = true;
+      (media_player.registration.on_event)(SessionEvent::Reconnected);
+    }
+
+    if self
+      .media_players
+      .values()
+      .all(|media_player| media_player.connected)
+    {
+      self.reconnect_at = None;
+      self.reconnect_delay = INITIAL_RECONNECT_DELAY;
+    } else {
+      self.reconnect_delay = (self.reconnect_delay * 2).min(MAXIMUM_RECONNECT_DELAY);
+      self.reconnect_at = Some(Instant::now() + self.reconnect_delay);
+    }
+  }
+
+  /// Names which media players have acquired or queued for
+  fn names_in_use(&self) -> Vec<String> {
+    self
+      .media_players
+      .values()
+      .filter(|media_player| media_player.connected)
+      .map(|media_player| media_player.name.to_owned())
+      .collect()
+  }
+
+  /// Sends a message from the connection of a media player, messages of media players which are not registered are dropped
+  fn send(&self, player_id: usize, message: Message) {
+    let connection = self
+      .media_players
+      .get(&player_id)
+      .filter(|media_player| media_player.connected)
+      .and_then(|media_player| media_player.connection.as_ref());
+    if let Some(connection) = connection {
+      let _ = connection.send(message);
+    }
+  }
+}
+
+fn release_name(target: &BusTarget, media_player: &RegisteredMediaPlayer) -> bool {
+  if matches!(target, BusTarget::Private(_) | BusTarget::Mock) {
+    return true;
+  }
+
+  media_player.connection.as_ref().is_some_and(|connection| {
+    matches!(
+      connection.release_name(media_player.name.as_str()),
+      Ok(ReleaseNameReply::Released)
+    )
+  })
+}
+
+/// Opens a new connection to the target, returning the reason reported by libdbus when it fails
+fn connect(target: &BusTarget) -> Result<Connection, String> {
+  let channel = match target {
+    BusTarget::Session => Channel::get_private(BusType::Session),
+    BusTarget::System => Channel::get_private(BusType::System),
+    BusTarget::Address(address) => Channel::open_private(address).and_then(|mut channel| {
+      channel.register()?;
+      Ok(channel)
+    }),
+    BusTarget::Private(address) => Channel::open_private(address),
+    BusTarget::Mock => return Err("The mock backend has no bus to connect to".to_string()),
+  };
+  let mut channel = channel.map_err(|error| {
+    error
+      .message()
+      .or(error.name())
+      .unwrap_or("Unknown error")
+      .to_string()
+  })?;
+  channel.set_watch_enabled(true);
+  Ok(Connection::from(channel))
+}
+
+/// Requests the name following the ownership policy
+///
+/// Returns the requested name and whether it is owned or only queued for. Names in use by other media players of the session count as taken without asking the bus, so media players never take over each other's names. A peer has no bus to own names on so the name is always considered owned.
+fn acquire_name(
+  target: &BusTarget,
+  connection: &Connection,
+  name: &str,
+  ownership: NameOwnership,
+  names_in_use: &[String],
+) -> Option<(String, bool)> {
+  if matches!(target, BusTarget::Private(_)) {
+    return Some((name.to_string(), true));
+  }
+
+  let request = |name: &str| {
+    if names_in_use.iter().any(|name_in_use| name_in_use == name) {
+      return None;
+    }
+
+    connection
+      .request_name(
+        name,
+        ownership.allow_replacement,
+        ownership.replace_existing,
+        !ownership.queue,
+      )
+      .ok()
+  };
+
+  match request(name) {
+    Some(RequestNameReply::PrimaryOwner) => return Some((name.to_string(), true)),
+    Some(RequestNameReply::InQueue) => return Some((name.to_string(), false)),
+    _ => {}
+  }
+
+  if ownership.instance_fallback {
+    let instance_name = format!("{}.instance{}", name, std::process::id());
+    if let Some(RequestNameReply::PrimaryOwner) = request(&instance_name) {
+      return Some((instance_name, true));
+    }
+  }
+
+  None
+}
+
+/// Tracks ownership changes of the name of a media player announced by the bus through NameAcquired and NameLost
+fn handle_name_signal(
+  registration: &MediaPlayerRegistration,
+  name: &str,
+  owns_name: &mut bool,
+  message: &Message,
+) {
+  if message.interface().as_deref() != Some("org.freedesktop.DBus") {
+    return;
+  }
+  let (Some(member), Some(signal_name)) = (message.member(), message.get1::<String>()) else {
+    return;
+  };
+  if signal_name != name {
+    return;
+  }
+
+  match &*member {
+    "NameAcquired" if !*owns_name => {
+      *owns_name = true;
+      (registration.on_event)(SessionEvent::NameAcquired(signal_name));
+    }
+    "NameLost" if *owns_name => {
+      *owns_name = false;
+      (registration.on_event)(SessionEvent::NameLost(signal_name));
+    }
+    _ => {}
+  }
+}
+
+/// Blocks until one of the file descriptors has one of its events ready or the timeout elapses
+fn wait(fds: &[(i32, i16)], timeout: Option<Duration>) {
+  let mut poll_fds: Vec<libc::pollfd> = fds
+    .iter()
+    .map(|(fd, events)| libc::pollfd {
+      fd: *fd,
+      events: *events,
+      revents: 0,
+    })
+    .collect();
+  let timeout = timeout.map_or(-1, |timeout| {
+    // Round up so the deadline has passed once poll returns
+    timeout.as_nanos().div_ceil(1_000_000).min(i32::MAX as u128) as i32
+  });
+  unsafe {
+    libc::poll(
+      poll_fds.as_mut_ptr(),
+      poll_fds.len() as libc::nfds_t,
+      timeout,
+    );
   }
 }
@@ -0,0 +1,47 @@
+use std::{
+  io::{self, Read, Write},
+  os::{
+    fd::{AsRawFd, RawFd},
+    unix::net::UnixStream,
+  },
+  sync::Arc,
+};
+
+/// Wakes the D-Bus thread while it is blocked waiting on the connection
+#[derive(Clone)]
+pub struct Waker {
+  reader: Arc<UnixStream>,
+  writer: Arc<UnixStream>,
+}
+
+impl Waker {
+  pub fn new() -> io::Result<Self> {
+    let (reader, writer) = UnixStream::pair()?;
+    reader.set_nonblocking(true)?;
+    writer.set_nonblocking(true)?;
+    Ok(Self {
+      reader: Arc::new(reader),
+      writer: Arc::new(writer),
+    })
+  }
+
+  pub fn wake(&self) {
+    // A full buffer already guarantees a pending wake up so the error can be ignored
+    let _ = (&*self.writer).write(&[1]);
+  }
+
+  /// Clears pending wake ups
+  pub fn drain(&self) {
+    let mut buffer = [0; 64];
+    while let Ok(read) = (&*self.reader).read(&mut buffer) {
+      if read == 0 {
+        break;
+      }
+    }
+  }
+
+  /// File descriptor which becomes readable when woken
+  pub fn fd(&self) -> RawFd {
+    self.reader.as_raw_fd()
+  }
+}
@@ -1,34 +1,89 @@
+mod clock;
 mod dbus;
 
 use std::{
-  sync::{Arc, RwLock},
-  time::{Duration, Instant},
+  cmp::Ordering,
+  collections::BTreeMap,
+  sync::{
+    mpsc::{self, RecvTimeoutError},
+    Arc, RwLock,
+  },
+  thread,
+  time::Duration,
 };
 
 use ::dbus::{
-  arg::{PropMap, Variant},
-  blocking::stdintf::org_freedesktop_dbus::{EmitsChangedSignal, PropertiesPropertiesChanged},
+  arg::{PropMap, RefArg, Variant},
+  blocking::stdintf::org_freedesktop_dbus::EmitsChangedSignal,
   message::SignalArgs,
-  MethodErr, Path,
+  Message, MethodErr, Path,
 };
-use dashmap::DashMap;
+use dashmap::{mapref::entry::Entry, DashMap};
 use dbus_crossroads::Crossroads;
 use float_duration::FloatDuration;
 use napi::{
-  bindgen_prelude::ObjectFinalize,
-  threadsafe_function::{ErrorStrategy, ThreadsafeFunction, ThreadsafeFunctionCallMode},
-  Env, JsFunction, NapiRaw,
+  bindgen_prelude::{AsyncTask, ClassInstance, ObjectFinalize},
+  threadsafe_function::{
+    ErrorStrategy, ThreadSafeCallContext, ThreadsafeFunction, ThreadsafeFunctionCallMode,
+  },
+  Env, JsBoolean, JsDate, JsFunction, JsNumber, JsObject, JsString, JsUnknown, NapiRaw, Task,
+  ValueType,
 };
 
+use crate::backend::{self, MediaPlayerBackend};
+use crate::error::{self, XosmsError, XosmsErrorCode};
+
+use self::clock::{ManualPlaybackClock, PlaybackClock, SystemPlaybackClock};
 use self::dbus::{
+  changes::{AutoFlush, PropertiesChangedQueue},
   mediaplayer2::{register_org_mpris_media_player2, OrgMprisMediaPlayer2},
   mediaplayer2_player::{
     register_org_mpris_media_player2_player, OrgMprisMediaPlayer2Player,
     OrgMprisMediaPlayer2PlayerSeeked,
   },
-  session::DBusSession,
+  mediaplayer2_playlists::{
+    register_org_mpris_media_player2_playlists, OrgMprisMediaPlayer2Playlists,
+    OrgMprisMediaPlayer2PlaylistsPlaylistChanged,
+  },
+  mediaplayer2_tracklist::{
+    register_org_mpris_media_player2_track_list, OrgMprisMediaPlayer2TrackList,
+    OrgMprisMediaPlayer2TrackListTrackAdded, OrgMprisMediaPlayer2TrackListTrackListReplaced,
+    OrgMprisMediaPlayer2TrackListTrackMetadataChanged, OrgMprisMediaPlayer2TrackListTrackRemoved,
+  },
+  session::{
+    BusTarget, DBusSession, MediaPlayerRegistration, NameOwnership, RegistrationCanceller,
+    RegistrationError, SessionEvent,
+  },
 };
 
+/// Uri to add, id of the track to insert after and whether to make it current
+type TrackAddArgs = (String, Option<String>, bool);
+
+/// Metadata keys set through the MediaPlayer properties, which custom metadata may not replace
+const STRUCTURED_METADATA_KEYS: [&str; 16] = [
+  "xesam:album",
+  "xesam:albumArtist",
+  "xesam:artist",
+  "xesam:comment",
+  "xesam:composer",
+  "xesam:contentCreated",
+  "xesam:discNumber",
+  "xesam:firstUsed",
+  "xesam:genre",
+  "xesam:lastUsed",
+  "xesam:lyricist",
+  "xesam:title",
+  "xesam:trackNumber",
+  "xesam:url",
+  "xesam:useCount",
+  "xesam:userRating",
+];
+
+const NO_TRACK_PATH: &str = "/org/mpris/MediaPlayer2/TrackList/NoTrack";
+
+/// Seconds to wait for the session bus when activating or deactivating
+const DEFAULT_BUS_TIMEOUT: f64 = 1.0;
+
 #[napi]
 #[derive(Debug, PartialEq, Eq)]
 pub enum MediaPlayerThumbnailType {
@@ -53,6 +108,199 @@ pub enum MediaPlayerPlaybackStatus {
   Stopped = 3,
 }
 
+#[napi]
+#[derive(Debug, PartialEq, Eq)]
+pub enum MediaPlayerRepeatMode {
+  Unknown = -1,
+  None = 1,
+  Track = 2,
+  Playlist = 3,
+}
+
+#[napi]
+#[derive(Debug, PartialEq, Eq)]
+pub enum MediaPlayerPlaylistOrdering {
+  Unknown = -1,
+  Alphabetical = 1,
+  CreationDate = 2,
+  ModifiedDate = 3,
+  LastPlayDate = 4,
+  UserDefined = 5,
+}
+
+#[napi]
+#[derive(Debug, PartialEq, Eq)]
+pub enum MediaPlayerConnectionState {
+  Disconnected = 1,
+  Connected = 2,
+  Reconnecting = 3,
+}
+
+#[napi]
+#[derive(Debug, PartialEq, Eq)]
+pub enum MediaPlayerBusType {
+  Session = 1,
+  System = 2,
+  /// Message bus listening on the bus address, in the format of `DBUS_SESSION_BUS_ADDRESS`
+  Address = 3,
+  /// Peer-to-peer connection to the bus address, without a message bus owning the service name
+  Private = 4,
+}
+
+#[napi(object)]
+#[derive(Clone)]
+pub struct MediaPlayerOptions {
+  /// Backend to use, defaults to the XOSMS_BACKEND environment variable or else Native. The bus options are ignored by the Mock backend
+  pub backend: Option<MediaPlayerBackend>,
+  /// Bus to serve the media player on, defaults to Session. Only used on Linux
+  pub bus_type: Option<MediaPlayerBusType>,
+  /// Address such as `unix:path=/run/user/1000/bus`, required by the Address and Private bus types
+  pub bus_address: Option<String>,
+}
+
+#[napi(object)]
+#[derive(Clone)]
+pub struct MediaPlayerTrack {
+  /// Unique id of the track within the track list
+  pub track_id: String,
+  /// Title of the track
+  pub title: Option<String>,
+  /// Artist of the track
+  pub artist: Option<String>,
+  /// Album title of the track
+  pub album_title: Option<String>,
+  /// Duration of the track in seconds
+  pub duration: Option<f64>,
+  /// Thumbnail uri of the track
+  pub thumbnail: Option<String>,
+}
+
+#[napi(object)]
+#[derive(Clone)]
+pub struct MediaPlayerPlaylist {
+  /// Unique id of the playlist
+  pub playlist_id: String,
+  /// Name of the playlist
+  pub name: String,
+  /// Icon uri of the playlist
+  pub icon: Option<String>,
+  /// Creation date of the playlist in milliseconds since the unix epoch
+  pub creation_date: Option<f64>,
+  /// Last modified date of the playlist in milliseconds since the unix epoch
+  pub modified_date: Option<f64>,
+  /// Last played date of the playlist in milliseconds since the unix epoch
+  pub last_play_date: Option<f64>,
+}
+
+#[napi(object)]
+#[derive(Clone)]
+pub struct MediaPlayerTimelineOptions {
+  /// Start time of the media in seconds, defaults to 0
+  pub start_time: Option<f64>,
+  /// Earliest position in seconds which can be seeked to, defaults to the start time
+  pub min_seek_time: Option<f64>,
+  /// Latest position in seconds which can be seeked to, defaults to the duration
+  pub max_seek_time: Option<f64>,
+  /// Whether to emit a seeked signal when the position jumps away from the expected position, defaults to true
+  pub detect_seek: Option<bool>,
+  /// Amount of seconds the position may differ from the expected position before it is considered a seek, defaults to 1
+  pub seek_threshold: Option<f64>,
+}
+
+#[napi(object)]
+#[derive(Clone)]
+pub struct MediaPlayerActivateOptions {
+  /// Whether another process may take over the service name, defaults to false
+  pub allow_replacement: Option<bool>,
+  /// Whether to take over the service name if its current owner allows replacement, defaults to true
+  pub replace_existing: Option<bool>,
+  /// Whether to wait in the queue for the service name when it is taken instead of failing, defaults to false
+  pub queue: Option<bool>,
+  /// Whether to fall back to the service name suffixed with `.instance<pid>` when it is taken, defaults to false
+  pub instance_fallback: Option<bool>,
+  /// Seconds to wait for the session bus before failing, defaults to 1
+  pub timeout: Option<f64>,
+}
+
+#[napi(object)]
+#[derive(Clone)]
+pub struct MediaPlayerActivation {
+  /// The service name which was acquired, or queued for
+  pub service_name: String,
+  /// Whether the service name was queued for as it is owned by another process
+  pub queued: bool,
+}
+
+#[napi(object)]
+#[derive(Clone)]
+pub struct MediaPlayerCapabilities {
+  /// Play button enabled state
+  pub play_button_enabled: Option<bool>,
+  /// Pause button enabled state
+  pub pause_button_enabled: Option<bool>,
+  /// Stop button enabled state
+  pub stop_button_enabled: Option<bool>,
+  /// Previous button enabled state
+  pub previous_button_enabled: Option<bool>,
+  /// Next button enabled state
+  pub next_button_enabled: Option<bool>,
+  /// Seek enabled state
+  pub seek_enabled: Option<bool>,
+}
+
+#[napi(object)]
+#[derive(Clone)]
+pub struct MediaPlayerPublishedState {
+  /// The service name which was acquired
+  pub service_name: String,
+  /// Playback status
+  pub playback_status: MediaPlayerPlaybackStatus,
+  /// Media title
+  pub title: String,
+  /// Media artists
+  pub artists: Vec<String>,
+  /// Media album title
+  pub album_title: String,
+  /// Track id
+  pub track_id: String,
+  /// Position in seconds
+  pub position: f64,
+  /// Playback rate
+  pub playback_rate: f64,
+  /// Volume
+  pub volume: f64,
+  /// Repeat mode
+  pub repeat_mode: MediaPlayerRepeatMode,
+  /// Shuffle state
+  pub shuffle: bool,
+  /// Button and seek enabled states
+  pub capabilities: MediaPlayerCapabilities,
+}
+
+#[napi(object, object_to_js = false)]
+struct MediaPlayerState {
+  /// Media title
+  pub title: Option<String>,
+  /// Media artists
+  pub artists: Option<Vec<String>>,
+  /// Media album title
+  pub album_title: Option<String>,
+  /// Track id of the media
+  pub track_id: Option<String>,
+  /// Duration of the media in seconds
+  pub duration: Option<f64>,
+  /// Position of the media in seconds
+  pub position: Option<f64>,
+  /// Thumbnail of the media
+  pub thumbnail: Option<ClassInstance<MediaPlayerThumbnail>>,
+  /// Playback status
+  pub playback_status: Option<MediaPlayerPlaybackStatus>,
+  /// Button enabled states
+  pub capabilities: Option<MediaPlayerCapabilities>,
+  /// Timeline options applied with the duration and position, defaults to the options of the last timeline update
+  pub timeline_options: Option<MediaPlayerTimelineOptions>,
+}
+
 #[napi]
 struct MediaPlayerThumbnail {
   thumbnail_type: MediaPlayerThumbnailType,
@@ -80,10 +328,18 @@ impl MediaPlayerThumbnail {
           thumbnail,
         });
       }
-      _ => Err(napi::Error::from_reason(format!(
-        "{:?} is not a valid MediaPlayerThumbnailType to create",
-        thumbnail_type
-      ))),
+      _ => Err(
+        XosmsError::new(
+          XosmsErrorCode::XOSMS_INVALID_ENUM,
+          format!(
+            "{:?} is not a valid MediaPlayerThumbnailType to create",
+            thumbnail_type
+          ),
+        )
+        .detail("value", thumbnail_type as i32)
+        .detail("enum", "MediaPlayerThumbnailType")
+        .into(),
+      ),
     }
   }
 
@@ -103,9 +359,48 @@ struct MediaPlayer {
     Arc<DashMap<usize, ThreadsafeFunction<f64, ErrorStrategy::CalleeHandled>>>,
   playback_position_seeked_listeners:
     Arc<DashMap<usize, ThreadsafeFunction<f64, ErrorStrategy::CalleeHandled>>>,
+  track_go_to_listeners:
+    Arc<DashMap<usize, ThreadsafeFunction<String, ErrorStrategy::CalleeHandled>>>,
+  track_add_listeners:
+    Arc<DashMap<usize, ThreadsafeFunction<TrackAddArgs, ErrorStrategy::CalleeHandled>>>,
+  track_remove_listeners:
+    Arc<DashMap<usize, ThreadsafeFunction<String, ErrorStrategy::CalleeHandled>>>,
+  playlist_activated_listeners:
+    Arc<DashMap<usize, ThreadsafeFunction<String, ErrorStrategy::CalleeHandled>>>,
+  repeat_mode_changed_listeners:
+    Arc<DashMap<usize, ThreadsafeFunction<MediaPlayerRepeatMode, ErrorStrategy::CalleeHandled>>>,
+  shuffle_changed_listeners:
+    Arc<DashMap<usize, ThreadsafeFunction<bool, ErrorStrategy::CalleeHandled>>>,
+  volume_changed_listeners:
+    Arc<DashMap<usize, ThreadsafeFunction<f64, ErrorStrategy::CalleeHandled>>>,
+  rate_changed_listeners:
+    Arc<DashMap<usize, ThreadsafeFunction<f64, ErrorStrategy::CalleeHandled>>>,
+  raise_listeners: Arc<DashMap<usize, ThreadsafeFunction<(), ErrorStrategy::CalleeHandled>>>,
+  quit_listeners: Arc<DashMap<usize, ThreadsafeFunction<(), ErrorStrategy::CalleeHandled>>>,
+  open_uri_listeners: Arc<DashMap<usize, ThreadsafeFunction<String, ErrorStrategy::CalleeHandled>>>,
+  fullscreen_changed_listeners:
+    Arc<DashMap<usize, ThreadsafeFunction<bool, ErrorStrategy::CalleeHandled>>>,
+  tick_listeners: Arc<DashMap<usize, ThreadsafeFunction<f64, ErrorStrategy::CalleeHandled>>>,
+  ended_listeners: Arc<DashMap<usize, ThreadsafeFunction<(), ErrorStrategy::CalleeHandled>>>,
+  bus_disconnected_listeners:
+    Arc<DashMap<usize, ThreadsafeFunction<(), ErrorStrategy::CalleeHandled>>>,
+  bus_reconnected_listeners:
+    Arc<DashMap<usize, ThreadsafeFunction<(), ErrorStrategy::CalleeHandled>>>,
+  name_acquired_listeners:
+    Arc<DashMap<usize, ThreadsafeFunction<String, ErrorStrategy::CalleeHandled>>>,
+  name_lost_listeners:
+    Arc<DashMap<usize, ThreadsafeFunction<String, ErrorStrategy::CalleeHandled>>>,
+  connection_state: Arc<RwLock<MediaPlayerConnectionState>>,
+  activation: Arc<RwLock<ActivationState>>,
   player_state: Arc<RwLock<MprisPlayerState>>,
-  properties_changed: PropertiesPropertiesChanged,
-  active: bool,
+  manual_clock: Option<Arc<ManualPlaybackClock>>,
+  tick_interval: Option<f64>,
+  tick_sender: Option<mpsc::Sender<Option<Duration>>>,
+  auto_flush_debounce: Option<f64>,
+  root_properties_changed: PropertiesChangedQueue,
+  properties_changed: PropertiesChangedQueue,
+  track_list_properties_changed: PropertiesChangedQueue,
+  playlists_properties_changed: PropertiesChangedQueue,
   dbus_session: DBusSession,
 }
 
@@ -113,7 +408,23 @@ struct MediaPlayer {
 impl MediaPlayer {
   #[napi(constructor)]
   #[allow(dead_code)]
-  pub fn new(service_name: String, identity: String) -> napi::Result<Self> {
+  pub fn new(
+    env: Env,
+    service_name: String,
+    identity: String,
+    options: Option<MediaPlayerOptions>,
+  ) -> napi::Result<Self> {
+    error::register_env(env);
+    validate_service_name(&service_name)?;
+    let bus_target = bus_target(options)?;
+    // The mock backend only moves the position when its clock is advanced
+    let manual_clock =
+      (bus_target == BusTarget::Mock).then(|| Arc::new(ManualPlaybackClock::new()));
+    let clock: Arc<dyn PlaybackClock> = match &manual_clock {
+      Some(manual_clock) => manual_clock.clone(),
+      None => Arc::new(SystemPlaybackClock::new()),
+    };
+
     let button_pressed_listeners: Arc<
       DashMap<usize, ThreadsafeFunction<String, ErrorStrategy::CalleeHandled>>,
     > = Arc::new(DashMap::new());
@@ -123,100 +434,550 @@ impl MediaPlayer {
     let playback_position_seeked_listeners: Arc<
       DashMap<usize, ThreadsafeFunction<f64, ErrorStrategy::CalleeHandled>>,
     > = Arc::new(DashMap::new());
+    let track_go_to_listeners: Arc<
+      DashMap<usize, ThreadsafeFunction<String, ErrorStrategy::CalleeHandled>>,
+    > = Arc::new(DashMap::new());
+    let track_add_listeners: Arc<
+      DashMap<usize, ThreadsafeFunction<TrackAddArgs, ErrorStrategy::CalleeHandled>>,
+    > = Arc::new(DashMap::new());
+    let track_remove_listeners: Arc<
+      DashMap<usize, ThreadsafeFunction<String, ErrorStrategy::CalleeHandled>>,
+    > = Arc::new(DashMap::new());
+    let playlist_activated_listeners: Arc<
+      DashMap<usize, ThreadsafeFunction<String, ErrorStrategy::CalleeHandled>>,
+    > = Arc::new(DashMap::new());
+    let repeat_mode_changed_listeners: Arc<
+      DashMap<usize, ThreadsafeFunction<MediaPlayerRepeatMode, ErrorStrategy::CalleeHandled>>,
+    > = Arc::new(DashMap::new());
+    let shuffle_changed_listeners: Arc<
+      DashMap<usize, ThreadsafeFunction<bool, ErrorStrategy::CalleeHandled>>,
+    > = Arc::new(DashMap::new());
+    let volume_changed_listeners: Arc<
+      DashMap<usize, ThreadsafeFunction<f64, ErrorStrategy::CalleeHandled>>,
+    > = Arc::new(DashMap::new());
+    let rate_changed_listeners: Arc<
+      DashMap<usize, ThreadsafeFunction<f64, ErrorStrategy::CalleeHandled>>,
+    > = Arc::new(DashMap::new());
+    let raise_listeners: Arc<DashMap<usize, ThreadsafeFunction<(), ErrorStrategy::CalleeHandled>>> =
+      Arc::new(DashMap::new());
+    let quit_listeners: Arc<DashMap<usize, ThreadsafeFunction<(), ErrorStrategy::CalleeHandled>>> =
+      Arc::new(DashMap::new());
+    let open_uri_listeners: Arc<
+      DashMap<usize, ThreadsafeFunction<String, ErrorStrategy::CalleeHandled>>,
+    > = Arc::new(DashMap::new());
+    let fullscreen_changed_listeners: Arc<
+      DashMap<usize, ThreadsafeFunction<bool, ErrorStrategy::CalleeHandled>>,
+    > = Arc::new(DashMap::new());
+    let tick_listeners: Arc<DashMap<usize, ThreadsafeFunction<f64, ErrorStrategy::CalleeHandled>>> =
+      Arc::new(DashMap::new());
+    let ended_listeners: Arc<DashMap<usize, ThreadsafeFunction<(), ErrorStrategy::CalleeHandled>>> =
+      Arc::new(DashMap::new());
+    let bus_disconnected_listeners: Arc<
+      DashMap<usize, ThreadsafeFunction<(), ErrorStrategy::CalleeHandled>>,
+    > = Arc::new(DashMap::new());
+    let bus_reconnected_listeners: Arc<
+      DashMap<usize, ThreadsafeFunction<(), ErrorStrategy::CalleeHandled>>,
+    > = Arc::new(DashMap::new());
+    let name_acquired_listeners: Arc<
+      DashMap<usize, ThreadsafeFunction<String, ErrorStrategy::CalleeHandled>>,
+    > = Arc::new(DashMap::new());
+    let name_lost_listeners: Arc<
+      DashMap<usize, ThreadsafeFunction<String, ErrorStrategy::CalleeHandled>>,
+    > = Arc::new(DashMap::new());
     let mpris_player_state = Arc::new(RwLock::new(MprisPlayerState {
       identity,
+      desktop_entry: "".to_string(),
+      fullscreen: false,
+      can_set_fullscreen: false,
       can_go_next: false,
       can_go_previous: false,
       can_play: false,
       can_pause: false,
       can_seek: false,
       can_control: true,
+      can_raise: false,
+      can_quit: false,
+      supported_uri_schemes: vec![],
+      supported_mime_types: vec![],
       media_type: MediaPlayerMediaType::Unknown,
       playback_status: MediaPlayerPlaybackStatus::Unknown,
+      repeat_mode: MediaPlayerRepeatMode::None,
+      shuffle: false,
       thumbnail: "".to_string(),
-      artist: "".to_string(),
+      artists: vec![],
+      album_artists: vec![],
       album_title: "".to_string(),
       title: "".to_string(),
       track_id: "".to_string(),
+      genres: vec![],
+      composers: vec![],
+      lyricists: vec![],
+      comments: vec![],
+      track_number: None,
+      disc_number: None,
+      user_rating: None,
+      use_count: None,
+      url: "".to_string(),
+      content_created: None,
+      first_used: None,
+      last_used: None,
+      custom_metadata: BTreeMap::new(),
       position: 0.0,
-      last_updated_position: Instant::now(),
+      last_updated_position: 0.0,
+      clock,
+      ended: false,
       duration: 0.0,
+      start_time: 0.0,
+      min_seek_time: 0.0,
+      max_seek_time: 0.0,
+      timeline_options: None,
       volume: 1.0,
       playback_rate: 1.0,
+      minimum_rate: 1.0,
+      maximum_rate: 1.0,
+      track_list: vec![],
+      can_edit_tracks: false,
+      playlists: vec![],
+      active_playlist_id: None,
+      playlist_orderings: vec![MediaPlayerPlaylistOrdering::UserDefined],
     }));
+    let dbus_session = DBusSession::new(bus_target);
 
     Ok(Self {
       service_name,
       button_pressed_listeners,
       playback_position_changed_listeners,
       playback_position_seeked_listeners,
+      track_go_to_listeners,
+      track_add_listeners,
+      track_remove_listeners,
+      playlist_activated_listeners,
+      repeat_mode_changed_listeners,
+      shuffle_changed_listeners,
+      volume_changed_listeners,
+      rate_changed_listeners,
+      raise_listeners,
+      quit_listeners,
+      open_uri_listeners,
+      fullscreen_changed_listeners,
+      tick_listeners,
+      ended_listeners,
+      bus_disconnected_listeners,
+      bus_reconnected_listeners,
+      name_acquired_listeners,
+      name_lost_listeners,
+      connection_state: Arc::new(RwLock::new(MediaPlayerConnectionState::Disconnected)),
+      activation: Arc::new(RwLock::new(ActivationState::default())),
       player_state: mpris_player_state,
-      properties_changed: PropertiesPropertiesChanged {
-        interface_name: "org.mpris.MediaPlayer2.Player".to_string(),
-        changed_properties: Default::default(),
-        invalidated_properties: vec![],
-      },
-      active: false,
-      dbus_session: DBusSession::new(),
+      manual_clock,
+      tick_interval: None,
+      tick_sender: None,
+      auto_flush_debounce: None,
+      root_properties_changed: PropertiesChangedQueue::new(
+        "org.mpris.MediaPlayer2",
+        dbus_session.waker(),
+      ),
+      properties_changed: PropertiesChangedQueue::new(
+        "org.mpris.MediaPlayer2.Player",
+        dbus_session.waker(),
+      ),
+      track_list_properties_changed: PropertiesChangedQueue::new(
+        "org.mpris.MediaPlayer2.TrackList",
+        dbus_session.waker(),
+      ),
+      playlists_properties_changed: PropertiesChangedQueue::new(
+        "org.mpris.MediaPlayer2.Playlists",
+        dbus_session.waker(),
+      ),
+      dbus_session,
     })
   }
 
   /// Activates the MediaPlayer allowing the operating system to see and use it
+  ///
+  /// Returns the service name which was acquired, or queued for when queueing is enabled and the name is taken. A 'nameacquired' event is emitted once a queued name is acquired.
   #[napi]
   #[allow(dead_code)]
-  pub fn activate(&mut self) -> napi::Result<()> {
-    if self.active {
-      return Ok(());
+  pub fn activate(&mut self, options: Option<MediaPlayerActivateOptions>) -> napi::Result<String> {
+    let mut task = self.start_activation(options)?;
+    Ok(task.wait()?.service_name)
+  }
+
+  /// Activates the MediaPlayer without blocking while waiting for the session bus
+  ///
+  /// Resolves with the service name which was acquired, or queued for, once the session bus responds.
+  #[napi(ts_return_type = "Promise<MediaPlayerActivation>")]
+  #[allow(dead_code)]
+  pub fn activate_async(
+    &mut self,
+    options: Option<MediaPlayerActivateOptions>,
+  ) -> napi::Result<AsyncTask<ActivateTask>> {
+    Ok(AsyncTask::new(self.start_activation(options)?))
+  }
+
+  /// Deactivates the MediaPlayer denying the operating system to see and use it
+  #[napi]
+  #[allow(dead_code)]
+  pub fn deactivate(&mut self) -> napi::Result<()> {
+    let mut task = self.start_deactivation(None)?;
+    // The service name is still released once the session bus responds
+    let _ = task.wait();
+    Ok(())
+  }
+
+  /// Deactivates the MediaPlayer without blocking while waiting for the session bus
+  ///
+  /// Resolves once the service name has been released, waiting for at most the timeout in seconds which defaults to 1.
+  #[napi(ts_return_type = "Promise<void>")]
+  #[allow(dead_code)]
+  pub fn deactivate_async(
+    &mut self,
+    timeout: Option<f64>,
+  ) -> napi::Result<AsyncTask<DeactivateTask>> {
+    Ok(AsyncTask::new(self.start_deactivation(timeout)?))
+  }
+
+  fn start_activation(
+    &mut self,
+    options: Option<MediaPlayerActivateOptions>,
+  ) -> napi::Result<ActivateTask> {
+    let options = options.unwrap_or(MediaPlayerActivateOptions {
+      allow_replacement: None,
+      replace_existing: None,
+      queue: None,
+      instance_fallback: None,
+      timeout: None,
+    });
+    let timeout = validate_timeout(options.timeout)?;
+    let ownership = NameOwnership {
+      allow_replacement: options.allow_replacement.unwrap_or(false),
+      replace_existing: options.replace_existing.unwrap_or(true),
+      queue: options.queue.unwrap_or(false),
+      instance_fallback: options.instance_fallback.unwrap_or(false),
+    };
+    if ownership.queue && ownership.instance_fallback {
+      return Err(
+        XosmsError::new(
+          XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+          "Queueing for the service name can not be combined with the instance fallback",
+        )
+        .into(),
+      );
     }
 
     let mut crossroads = Crossroads::new();
 
     let mpris_iface_token = register_org_mpris_media_player2(&mut crossroads);
     let mpris_player_iface_token = register_org_mpris_media_player2_player(&mut crossroads);
+    let mpris_track_list_iface_token = register_org_mpris_media_player2_track_list(&mut crossroads);
+    let mpris_playlists_iface_token = register_org_mpris_media_player2_playlists(&mut crossroads);
 
     crossroads.insert(
       "/org/mpris/MediaPlayer2",
-      &[mpris_iface_token, mpris_player_iface_token],
-      MprisPlayer {
-        button_pressed_listeners: self.button_pressed_listeners.clone(),
-        playback_position_changed_listeners: self.playback_position_changed_listeners.clone(),
-        playback_position_seeked_listeners: self.playback_position_seeked_listeners.clone(),
-        state: self.player_state.clone(),
-      },
+      &[
+        mpris_iface_token,
+        mpris_player_iface_token,
+        mpris_track_list_iface_token,
+        mpris_playlists_iface_token,
+      ],
+      self.mpris_player(),
     );
 
-    if !self.dbus_session.register(&self.service_name, crossroads) {
-      return Err(napi::Error::from_reason(
-        "Could not obtain service name on D-Bus",
-      ));
+    let connection_state = self.connection_state.clone();
+    let bus_disconnected_listeners = self.bus_disconnected_listeners.clone();
+    let bus_reconnected_listeners = self.bus_reconnected_listeners.clone();
+    let name_acquired_listeners = self.name_acquired_listeners.clone();
+    let name_lost_listeners = self.name_lost_listeners.clone();
+    let service_name = format!("org.mpris.MediaPlayer2.{}", &self.service_name);
+    let registration = MediaPlayerRegistration {
+      name: service_name.to_owned(),
+      ownership,
+      crossroads,
+      queues: self.properties_changed_queues(),
+      on_event: Box::new(move |event| match event {
+        SessionEvent::Disconnected | SessionEvent::Reconnected => {
+          let connected = matches!(event, SessionEvent::Reconnected);
+          if let Ok(mut connection_state) = connection_state.write() {
+            *connection_state = if connected {
+              MediaPlayerConnectionState::Connected
+            } else {
+              MediaPlayerConnectionState::Reconnecting
+            };
+          }
+
+          let listeners = if connected {
+            &bus_reconnected_listeners
+          } else {
+            &bus_disconnected_listeners
+          };
+          for listener in listeners.iter() {
+            listener.call(Ok(()), ThreadsafeFunctionCallMode::NonBlocking);
+          }
+        }
+        SessionEvent::NameAcquired(name) => {
+          for listener in name_acquired_listeners.iter() {
+            listener.call(Ok(name.to_owned()), ThreadsafeFunctionCallMode::NonBlocking);
+          }
+        }
+        SessionEvent::NameLost(name) => {
+          for listener in name_lost_listeners.iter() {
+            listener.call(Ok(name.to_owned()), ThreadsafeFunctionCallMode::NonBlocking);
+          }
+        }
+      }),
+    };
+
+    let mut activation_state = self
+      .activation
+      .write()
+      .unwrap_or_else(|error| error.into_inner());
+    let response = match &activation_state.activation {
+      Some(activation) => {
+        let (response_sender, response_receiver) = oneshot::channel();
+        let _ = response_sender.send(Ok((activation.service_name.to_owned(), !activation.queued)));
+        response_receiver
+      }
+      None => {
+        activation_state.generation += 1;
+        self.dbus_session.register(registration)
+      }
+    };
+
+    Ok(ActivateTask {
+      response,
+      service_name,
+      bus: self.dbus_session.target().to_string(),
+      timeout,
+      generation: activation_state.generation,
+      canceller: self.dbus_session.registration_canceller(),
+      activation: self.activation.clone(),
+      connection_state: self.connection_state.clone(),
+    })
+  }
+
+  fn start_deactivation(&mut self, timeout: Option<f64>) -> napi::Result<DeactivateTask> {
+    let timeout = validate_timeout(timeout)?;
+
+    let mut activation_state = self
+      .activation
+      .write()
+      .unwrap_or_else(|error| error.into_inner());
+    // Also cancels an activation which is still waiting for the session bus
+    activation_state.generation += 1;
+    activation_state.activation = None;
+    self.set_connection_state(MediaPlayerConnectionState::Disconnected);
+
+    Ok(DeactivateTask {
+      response: self.dbus_session.unregister(),
+      timeout,
+    })
+  }
+
+  fn mpris_player(&self) -> MprisPlayer {
+    MprisPlayer {
+      button_pressed_listeners: self.button_pressed_listeners.clone(),
+      playback_position_changed_listeners: self.playback_position_changed_listeners.clone(),
+      playback_position_seeked_listeners: self.playback_position_seeked_listeners.clone(),
+      track_go_to_listeners: self.track_go_to_listeners.clone(),
+      track_add_listeners: self.track_add_listeners.clone(),
+      track_remove_listeners: self.track_remove_listeners.clone(),
+      playlist_activated_listeners: self.playlist_activated_listeners.clone(),
+      repeat_mode_changed_listeners: self.repeat_mode_changed_listeners.clone(),
+      shuffle_changed_listeners: self.shuffle_changed_listeners.clone(),
+      volume_changed_listeners: self.volume_changed_listeners.clone(),
+      rate_changed_listeners: self.rate_changed_listeners.clone(),
+      raise_listeners: self.raise_listeners.clone(),
+      quit_listeners: self.quit_listeners.clone(),
+      open_uri_listeners: self.open_uri_listeners.clone(),
+      fullscreen_changed_listeners: self.fullscreen_changed_listeners.clone(),
+      state: self.player_state.clone(),
+    }
+  }
+
+  /// Gets the state of the connection to the session bus
+  ///
+  /// While reconnecting the media player is not visible to the operating system. Its name is requested again and its state re-broadcast once the bus is back.
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_connection_state(&self) -> napi::Result<MediaPlayerConnectionState> {
+    if let Ok(connection_state) = self.connection_state.read() {
+      return Ok(*connection_state);
+    }
+
+    Ok(MediaPlayerConnectionState::Disconnected)
+  }
+
+  fn set_connection_state(&self, state: MediaPlayerConnectionState) {
+    if let Ok(mut connection_state) = self.connection_state.write() {
+      *connection_state = state;
+    }
+  }
+
+  fn is_mock(&self) -> bool {
+    *self.dbus_session.target() == BusTarget::Mock
+  }
+
+  fn is_active(&self) -> bool {
+    self
+      .activation
+      .read()
+      .map(|activation_state| activation_state.activation.is_some())
+      .unwrap_or(false)
+  }
+
+  /// Simulates a media services button press, only available with the mock backend
+  ///
+  /// Presses are handled like they are from the operating system, so they are ignored while the media player is not activated or the button is disabled.
+  #[napi]
+  #[allow(dead_code)]
+  pub fn simulate_button(
+    &self,
+    #[napi(ts_arg_type = "'play' | 'pause' | 'playpause' | 'stop' | 'next' | 'previous'")]
+    button: String,
+  ) -> napi::Result<()> {
+    if !self.is_mock() {
+      return Err(backend::mock_only("simulateButton").into());
+    }
+    let mut player = self.mpris_player();
+    let pressed = match button.as_str() {
+      "play" => OrgMprisMediaPlayer2Player::play,
+      "pause" => OrgMprisMediaPlayer2Player::pause,
+      "playpause" => OrgMprisMediaPlayer2Player::play_pause,
+      "stop" => OrgMprisMediaPlayer2Player::stop,
+      "next" => OrgMprisMediaPlayer2Player::next,
+      "previous" => OrgMprisMediaPlayer2Player::previous,
+      _ => {
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+            format!("{} is not a media services button", button),
+          )
+          .detail("value", button)
+          .into(),
+        )
+      }
+    };
+    if self.is_active() {
+      // Presses of disabled buttons are rejected without reaching the listeners
+      let _ = pressed(&mut player);
     }
 
-    self.active = true;
     Ok(())
   }
 
-  /// Deactivates the MediaPlayer denying the operating system to see and use it
+  /// Simulates the media service requesting a position change in seconds for the current track, only available with the mock backend
+  ///
+  /// Positions outside of the seekable range are ignored, as is the request while the media player is not activated or seeking is disabled.
   #[napi]
   #[allow(dead_code)]
-  pub fn deactivate(&mut self) -> napi::Result<()> {
-    if self.active {
-      self.active = false;
-      self.dbus_session.unregister(&self.service_name);
+  pub fn simulate_position_change(&self, position: f64) -> napi::Result<()> {
+    if !self.is_mock() {
+      return Err(backend::mock_only("simulatePositionChange").into());
+    }
+    validate_simulated_seconds(position)?;
+    if !self.is_active() {
+      return Ok(());
+    }
+
+    let track_path = published_track_path(&self.get_track_id()?);
+    let _ = self
+      .mpris_player()
+      .set_position(track_path, seconds_to_microseconds(position));
+
+    Ok(())
+  }
+
+  /// Simulates the media service requesting a seek by the offset in seconds from the current position, only available with the mock backend
+  ///
+  /// The request is ignored while the media player is not activated or seeking is disabled.
+  #[napi]
+  #[allow(dead_code)]
+  pub fn simulate_seek(&self, offset: f64) -> napi::Result<()> {
+    if !self.is_mock() {
+      return Err(backend::mock_only("simulateSeek").into());
+    }
+    validate_simulated_seconds(offset)?;
+    if !self.is_active() {
+      return Ok(());
     }
+
+    let _ = self.mpris_player().seek(seconds_to_microseconds(offset));
+
     Ok(())
   }
 
+  /// Gets a snapshot of the state visible to the operating system, only available with the mock backend
+  ///
+  /// Returns null while the media player is not activated.
+  #[napi]
+  #[allow(dead_code)]
+  pub fn published_state(&self) -> napi::Result<Option<MediaPlayerPublishedState>> {
+    if !self.is_mock() {
+      return Err(backend::mock_only("publishedState").into());
+    }
+    let activation = self
+      .activation
+      .read()
+      .ok()
+      .and_then(|activation_state| activation_state.activation.clone());
+    let Some(activation) = activation else {
+      return Ok(None);
+    };
+
+    Ok(Some(MediaPlayerPublishedState {
+      service_name: activation.service_name,
+      playback_status: self.get_playback_status()?,
+      title: self.get_title()?,
+      artists: self.get_artists()?,
+      album_title: self.get_album_title()?,
+      track_id: self.get_track_id()?,
+      position: self.get_position()?,
+      playback_rate: self.get_playback_rate()?,
+      volume: self.get_volume()?,
+      repeat_mode: self.get_repeat_mode()?,
+      shuffle: self.get_shuffle()?,
+      capabilities: MediaPlayerCapabilities {
+        play_button_enabled: Some(self.get_play_button_enabled()?),
+        pause_button_enabled: Some(self.get_pause_button_enabled()?),
+        stop_button_enabled: Some(self.get_stop_button_enabled()?),
+        previous_button_enabled: Some(self.get_previous_button_enabled()?),
+        next_button_enabled: Some(self.get_next_button_enabled()?),
+        seek_enabled: Some(self.get_seek_enabled()?),
+      },
+    }))
+  }
+
   /// Adds an event listener to the MediaPlayer
   ///
   /// 'buttonpressed' - Emitted when a media services button is pressed
   /// 'positionchanged' - Emitted when the media service requests a position change
   /// 'positionseeked' - Emitted when the media service requests a forward or backward position seek from current position
+  /// 'trackgoto' - Emitted when the media service requests to skip to a track in the track list
+  /// 'trackadd' - Emitted when the media service requests a uri to be added to the track list
+  /// 'trackremove' - Emitted when the media service requests a track to be removed from the track list
+  /// 'playlistactivated' - Emitted when the media service requests a playlist to be activated
+  /// 'repeatmodechanged' - Emitted when the media service requests a repeat mode change
+  /// 'shufflechanged' - Emitted when the media service requests shuffle to be enabled or disabled
+  /// 'volumechanged' - Emitted when the media service requests a volume change
+  /// 'ratechanged' - Emitted when the media service requests a playback rate change
+  /// 'raise' - Emitted when the media service requests the media player to be brought to the front
+  /// 'quit' - Emitted when the media service requests the media player to quit
+  /// 'openuri' - Emitted when the media service requests a uri to be opened
+  /// 'fullscreenchanged' - Emitted when the media service requests fullscreen to be enabled or disabled
+  /// 'tick' - Emitted every tick interval with the current position while playing
+  /// 'ended' - Emitted when the position reaches the duration while playing, requires a tick interval
+  /// 'busdisconnected' - Emitted when the connection to the session bus is lost while active
+  /// 'busreconnected' - Emitted when the media player is visible again after the connection to the session bus was restored
+  /// 'nameacquired' - Emitted with the service name when a queued for service name is acquired
+  /// 'namelost' - Emitted with the service name when another process takes over the service name
   #[napi]
   #[allow(dead_code)]
   pub fn add_event_listener(
     &mut self,
     env: Env,
-    #[napi(ts_arg_type = "'buttonpressed' | 'positionchanged' | 'positionseeked'")]
+    #[napi(
+      ts_arg_type = "'buttonpressed' | 'positionchanged' | 'positionseeked' | 'trackgoto' | 'trackadd' | 'trackremove' | 'playlistactivated' | 'repeatmodechanged' | 'shufflechanged' | 'volumechanged' | 'ratechanged' | 'raise' | 'quit' | 'openuri' | 'fullscreenchanged' | 'tick' | 'ended' | 'busdisconnected' | 'busreconnected' | 'nameacquired' | 'namelost'"
+    )]
     event_name: String,
     callback: JsFunction,
   ) -> napi::Result<()> {
@@ -262,6 +1023,175 @@ impl MediaPlayer {
             .insert(callback_ptr, threadsafe_callback);
         }
       }
+      "trackgoto" => {
+        if let Entry::Vacant(entry) = self.track_go_to_listeners.entry(callback_ptr) {
+          let mut threadsafe_callback = callback.create_threadsafe_function(0, |ctx| {
+            ctx.env.create_string_from_std(ctx.value).map(|v| vec![v])
+          })?;
+          threadsafe_callback.unref(&env)?;
+          entry.insert(threadsafe_callback);
+        }
+      }
+      "trackadd" => {
+        if let Entry::Vacant(entry) = self.track_add_listeners.entry(callback_ptr) {
+          let mut threadsafe_callback = callback.create_threadsafe_function(
+            0,
+            |ctx: ThreadSafeCallContext<TrackAddArgs>| {
+              let (uri, after_track_id, set_as_current) = ctx.value;
+              Ok(vec![
+                ctx.env.create_string_from_std(uri)?.into_unknown(),
+                match after_track_id {
+                  Some(after_track_id) => ctx
+                    .env
+                    .create_string_from_std(after_track_id)?
+                    .into_unknown(),
+                  None => ctx.env.get_null()?.into_unknown(),
+                },
+                ctx.env.get_boolean(set_as_current)?.into_unknown(),
+              ])
+            },
+          )?;
+          threadsafe_callback.unref(&env)?;
+          entry.insert(threadsafe_callback);
+        }
+      }
+      "trackremove" => {
+        if let Entry::Vacant(entry) = self.track_remove_listeners.entry(callback_ptr) {
+          let mut threadsafe_callback = callback.create_threadsafe_function(0, |ctx| {
+            ctx.env.create_string_from_std(ctx.value).map(|v| vec![v])
+          })?;
+          threadsafe_callback.unref(&env)?;
+          entry.insert(threadsafe_callback);
+        }
+      }
+      "playlistactivated" => {
+        if let Entry::Vacant(entry) = self.playlist_activated_listeners.entry(callback_ptr) {
+          let mut threadsafe_callback = callback.create_threadsafe_function(0, |ctx| {
+            ctx.env.create_string_from_std(ctx.value).map(|v| vec![v])
+          })?;
+          threadsafe_callback.unref(&env)?;
+          entry.insert(threadsafe_callback);
+        }
+      }
+      "repeatmodechanged" => {
+        if let Entry::Vacant(entry) = self.repeat_mode_changed_listeners.entry(callback_ptr) {
+          let mut threadsafe_callback = callback.create_threadsafe_function(0, |ctx| {
+            ctx.env.create_int32(ctx.value as i32).map(|v| vec![v])
+          })?;
+          threadsafe_callback.unref(&env)?;
+          entry.insert(threadsafe_callback);
+        }
+      }
+      "shufflechanged" => {
+        if let Entry::Vacant(entry) = self.shuffle_changed_listeners.entry(callback_ptr) {
+          let mut threadsafe_callback = callback
+            .create_threadsafe_function(0, |ctx| ctx.env.get_boolean(ctx.value).map(|v| vec![v]))?;
+          threadsafe_callback.unref(&env)?;
+          entry.insert(threadsafe_callback);
+        }
+      }
+      "volumechanged" => {
+        if let Entry::Vacant(entry) = self.volume_changed_listeners.entry(callback_ptr) {
+          let mut threadsafe_callback = callback.create_threadsafe_function(0, |ctx| {
+            ctx.env.create_double(ctx.value).map(|v| vec![v])
+          })?;
+          threadsafe_callback.unref(&env)?;
+          entry.insert(threadsafe_callback);
+        }
+      }
+      "ratechanged" => {
+        if let Entry::Vacant(entry) = self.rate_changed_listeners.entry(callback_ptr) {
+          let mut threadsafe_callback = callback.create_threadsafe_function(0, |ctx| {
+            ctx.env.create_double(ctx.value).map(|v| vec![v])
+          })?;
+          threadsafe_callback.unref(&env)?;
+          entry.insert(threadsafe_callback);
+        }
+      }
+      "raise" => {
+        if let Entry::Vacant(entry) = self.raise_listeners.entry(callback_ptr) {
+          let mut threadsafe_callback = callback
+            .create_threadsafe_function(0, |ctx| ctx.env.get_undefined().map(|v| vec![v]))?;
+          threadsafe_callback.unref(&env)?;
+          entry.insert(threadsafe_callback);
+        }
+      }
+      "quit" => {
+        if let Entry::Vacant(entry) = self.quit_listeners.entry(callback_ptr) {
+          let mut threadsafe_callback = callback
+            .create_threadsafe_function(0, |ctx| ctx.env.get_undefined().map(|v| vec![v]))?;
+          threadsafe_callback.unref(&env)?;
+          entry.insert(threadsafe_callback);
+        }
+      }
+      "openuri" => {
+        if let Entry::Vacant(entry) = self.open_uri_listeners.entry(callback_ptr) {
+          let mut threadsafe_callback = callback.create_threadsafe_function(0, |ctx| {
+            ctx.env.create_string_from_std(ctx.value).map(|v| vec![v])
+          })?;
+          threadsafe_callback.unref(&env)?;
+          entry.insert(threadsafe_callback);
+        }
+      }
+      "fullscreenchanged" => {
+        if let Entry::Vacant(entry) = self.fullscreen_changed_listeners.entry(callback_ptr) {
+          let mut threadsafe_callback = callback
+            .create_threadsafe_function(0, |ctx| ctx.env.get_boolean(ctx.value).map(|v| vec![v]))?;
+          threadsafe_callback.unref(&env)?;
+          entry.insert(threadsafe_callback);
+        }
+      }
+      "tick" => {
+        if let Entry::Vacant(entry) = self.tick_listeners.entry(callback_ptr) {
+          let mut threadsafe_callback = callback.create_threadsafe_function(0, |ctx| {
+            ctx.env.create_double(ctx.value).map(|v| vec![v])
+          })?;
+          threadsafe_callback.unref(&env)?;
+          entry.insert(threadsafe_callback);
+        }
+      }
+      "ended" => {
+        if let Entry::Vacant(entry) = self.ended_listeners.entry(callback_ptr) {
+          let mut threadsafe_callback = callback
+            .create_threadsafe_function(0, |ctx| ctx.env.get_undefined().map(|v| vec![v]))?;
+          threadsafe_callback.unref(&env)?;
+          entry.insert(threadsafe_callback);
+        }
+      }
+      "busdisconnected" => {
+        if let Entry::Vacant(entry) = self.bus_disconnected_listeners.entry(callback_ptr) {
+          let mut threadsafe_callback = callback
+            .create_threadsafe_function(0, |ctx| ctx.env.get_undefined().map(|v| vec![v]))?;
+          threadsafe_callback.unref(&env)?;
+          entry.insert(threadsafe_callback);
+        }
+      }
+      "busreconnected" => {
+        if let Entry::Vacant(entry) = self.bus_reconnected_listeners.entry(callback_ptr) {
+          let mut threadsafe_callback = callback
+            .create_threadsafe_function(0, |ctx| ctx.env.get_undefined().map(|v| vec![v]))?;
+          threadsafe_callback.unref(&env)?;
+          entry.insert(threadsafe_callback);
+        }
+      }
+      "nameacquired" => {
+        if let Entry::Vacant(entry) = self.name_acquired_listeners.entry(callback_ptr) {
+          let mut threadsafe_callback = callback.create_threadsafe_function(0, |ctx| {
+            ctx.env.create_string_from_std(ctx.value).map(|v| vec![v])
+          })?;
+          threadsafe_callback.unref(&env)?;
+          entry.insert(threadsafe_callback);
+        }
+      }
+      "namelost" => {
+        if let Entry::Vacant(entry) = self.name_lost_listeners.entry(callback_ptr) {
+          let mut threadsafe_callback = callback.create_threadsafe_function(0, |ctx| {
+            ctx.env.create_string_from_std(ctx.value).map(|v| vec![v])
+          })?;
+          threadsafe_callback.unref(&env)?;
+          entry.insert(threadsafe_callback);
+        }
+      }
       _ => {}
     };
 
@@ -273,7 +1203,9 @@ impl MediaPlayer {
   #[allow(dead_code)]
   pub fn remove_event_listener(
     &mut self,
-    #[napi(ts_arg_type = "'buttonpressed' | 'positionchanged' | 'positionseeked'")]
+    #[napi(
+      ts_arg_type = "'buttonpressed' | 'positionchanged' | 'positionseeked' | 'trackgoto' | 'trackadd' | 'trackremove' | 'playlistactivated' | 'repeatmodechanged' | 'shufflechanged' | 'volumechanged' | 'ratechanged' | 'raise' | 'quit' | 'openuri' | 'fullscreenchanged' | 'tick' | 'ended' | 'busdisconnected' | 'busreconnected' | 'nameacquired' | 'namelost'"
+    )]
     event_name: String,
     callback: JsFunction,
   ) -> napi::Result<()> {
@@ -305,6 +1237,60 @@ impl MediaPlayer {
             .remove(&callback_ptr);
         }
       }
+      "trackgoto" => {
+        self.track_go_to_listeners.remove(&callback_ptr);
+      }
+      "trackadd" => {
+        self.track_add_listeners.remove(&callback_ptr);
+      }
+      "trackremove" => {
+        self.track_remove_listeners.remove(&callback_ptr);
+      }
+      "playlistactivated" => {
+        self.playlist_activated_listeners.remove(&callback_ptr);
+      }
+      "repeatmodechanged" => {
+        self.repeat_mode_changed_listeners.remove(&callback_ptr);
+      }
+      "shufflechanged" => {
+        self.shuffle_changed_listeners.remove(&callback_ptr);
+      }
+      "volumechanged" => {
+        self.volume_changed_listeners.remove(&callback_ptr);
+      }
+      "ratechanged" => {
+        self.rate_changed_listeners.remove(&callback_ptr);
+      }
+      "raise" => {
+        self.raise_listeners.remove(&callback_ptr);
+      }
+      "quit" => {
+        self.quit_listeners.remove(&callback_ptr);
+      }
+      "openuri" => {
+        self.open_uri_listeners.remove(&callback_ptr);
+      }
+      "fullscreenchanged" => {
+        self.fullscreen_changed_listeners.remove(&callback_ptr);
+      }
+      "tick" => {
+        self.tick_listeners.remove(&callback_ptr);
+      }
+      "ended" => {
+        self.ended_listeners.remove(&callback_ptr);
+      }
+      "busdisconnected" => {
+        self.bus_disconnected_listeners.remove(&callback_ptr);
+      }
+      "busreconnected" => {
+        self.bus_reconnected_listeners.remove(&callback_ptr);
+      }
+      "nameacquired" => {
+        self.name_acquired_listeners.remove(&callback_ptr);
+      }
+      "namelost" => {
+        self.name_lost_listeners.remove(&callback_ptr);
+      }
       _ => {}
     };
 
@@ -319,7 +1305,9 @@ impl MediaPlayer {
   pub fn on(
     &mut self,
     env: Env,
-    #[napi(ts_arg_type = "'buttonpressed' | 'positionchanged' | 'positionseeked'")]
+    #[napi(
+      ts_arg_type = "'buttonpressed' | 'positionchanged' | 'positionseeked' | 'trackgoto' | 'trackadd' | 'trackremove' | 'playlistactivated' | 'repeatmodechanged' | 'shufflechanged' | 'volumechanged' | 'ratechanged' | 'raise' | 'quit' | 'openuri' | 'fullscreenchanged' | 'tick' | 'ended' | 'busdisconnected' | 'busreconnected' | 'nameacquired' | 'namelost'"
+    )]
     event_name: String,
     callback: JsFunction,
   ) -> napi::Result<()> {
@@ -333,7 +1321,9 @@ impl MediaPlayer {
   #[allow(dead_code)]
   pub fn off(
     &mut self,
-    #[napi(ts_arg_type = "'buttonpressed' | 'positionchanged' | 'positionseeked'")]
+    #[napi(
+      ts_arg_type = "'buttonpressed' | 'positionchanged' | 'positionseeked' | 'trackgoto' | 'trackadd' | 'trackremove' | 'playlistactivated' | 'repeatmodechanged' | 'shufflechanged' | 'volumechanged' | 'ratechanged' | 'raise' | 'quit' | 'openuri' | 'fullscreenchanged' | 'tick' | 'ended' | 'busdisconnected' | 'busreconnected' | 'nameacquired' | 'namelost'"
+    )]
     event_name: String,
     callback: JsFunction,
   ) -> napi::Result<()> {
@@ -344,13 +1334,223 @@ impl MediaPlayer {
   #[napi]
   #[allow(dead_code)]
   pub fn update(&mut self) -> napi::Result<()> {
-    self.dbus_session.emit_message(
-      self
-        .properties_changed
-        .to_emit_message(&Path::new("/org/mpris/MediaPlayer2").unwrap()),
-    );
-    self.properties_changed.changed_properties.clear();
-    self.properties_changed.invalidated_properties.clear();
+    for queue in self.properties_changed_queues() {
+      if let Some(message) = queue.take_message() {
+        self.dbus_session.emit_message(message);
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Immediately emits the accumulated changes without waiting for the auto flush debounce window
+  #[napi]
+  #[allow(dead_code)]
+  pub fn flush_now(&mut self) -> napi::Result<()> {
+    self.update()
+  }
+
+  /// Gets the auto flush debounce window in seconds
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_auto_flush_debounce(&self) -> napi::Result<Option<f64>> {
+    Ok(self.auto_flush_debounce)
+  }
+
+  /// Sets the auto flush debounce window in seconds
+  ///
+  /// When set, accumulated changes are emitted automatically once the debounce window after the first change has passed. When null, changes are only emitted on update.
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_auto_flush_debounce(&mut self, auto_flush_debounce: Option<f64>) -> napi::Result<()> {
+    let debounce = match auto_flush_debounce {
+      Some(auto_flush_debounce) => Some(
+        Duration::try_from_secs_f64(auto_flush_debounce)
+          .ok()
+          .filter(|_| auto_flush_debounce >= 0.0)
+          .ok_or_else(|| {
+            XosmsError::new(
+              XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+              format!(
+                "{} is not a valid auto flush debounce window, it must be a finite number of 0 or greater",
+                auto_flush_debounce
+              ),
+            )
+            .detail("value", auto_flush_debounce)
+          })?,
+      ),
+      None => None,
+    };
+
+    self.auto_flush_debounce = auto_flush_debounce;
+    self.dbus_session.set_auto_flush(
+      debounce.map(|debounce| AutoFlush::new(self.properties_changed_queues(), debounce)),
+    );
+
+    Ok(())
+  }
+
+  /// Sets multiple properties at once
+  ///
+  /// Every provided value is validated before any of them are applied so a validation failure leaves the state untouched. Omitted values are left unchanged.
+  ///
+  /// The changes are queued as a single PropertiesChanged which is emitted on the next update. A seeked signal is emitted when the position jumps by more than the seek threshold of the timeline options, unless seek detection is turned off.
+  #[napi]
+  #[allow(dead_code)]
+  pub fn set_state(&mut self, state: MediaPlayerState) -> napi::Result<()> {
+    if state.playback_status == Some(MediaPlayerPlaybackStatus::Unknown) {
+      return Err(
+        XosmsError::new(
+          XosmsErrorCode::XOSMS_INVALID_ENUM,
+          format!(
+            "{:?} is not a valid MediaPlayerPlaybackStatus to set",
+            MediaPlayerPlaybackStatus::Unknown
+          ),
+        )
+        .detail("value", MediaPlayerPlaybackStatus::Unknown as i32)
+        .detail("enum", "MediaPlayerPlaybackStatus")
+        .into(),
+      );
+    }
+    if let Some(track_id) = state
+      .track_id
+      .as_ref()
+      .filter(|track_id| !track_id.is_empty())
+    {
+      track_id_to_path(track_id)?;
+    }
+
+    let mut seeked = None;
+    if let Ok(mut player_state) = self.player_state.write() {
+      let timeline = if state.duration.is_some() || state.position.is_some() {
+        let duration = state.duration.unwrap_or(player_state.duration);
+        let position = state
+          .position
+          .unwrap_or_else(|| current_position(&player_state));
+        let options = state
+          .timeline_options
+          .clone()
+          .or_else(|| player_state.timeline_options.clone());
+        let bounds = validate_timeline(duration, position, &options)?;
+        Some((duration, position, options, bounds))
+      } else {
+        None
+      };
+
+      let new_track = state
+        .track_id
+        .as_ref()
+        .is_some_and(|track_id| *track_id != player_state.track_id);
+      if let Some((duration, position, options, bounds)) = timeline {
+        let detect_seek = options
+          .as_ref()
+          .and_then(|options| options.detect_seek)
+          .unwrap_or(true);
+        let seek_threshold = options
+          .as_ref()
+          .and_then(|options| options.seek_threshold)
+          .unwrap_or(1.0);
+        // A changed track or duration is treated as a new track rather than a seek
+        if detect_seek
+          && !new_track
+          && player_state.duration == duration
+          && (position - current_position(&player_state)).abs() > seek_threshold
+        {
+          seeked = Some(position);
+        }
+
+        player_state.duration = duration;
+        player_state.start_time = bounds.start_time;
+        player_state.min_seek_time = bounds.min_seek_time;
+        player_state.max_seek_time = bounds.max_seek_time;
+        player_state.timeline_options = options;
+        player_state.position = position;
+        player_state.last_updated_position = player_state.clock.now();
+        player_state.ended = false;
+      }
+      if let Some(title) = state.title.clone() {
+        player_state.title = title;
+      }
+      if let Some(artists) = state.artists.clone() {
+        player_state.artists = artists;
+      }
+      if let Some(album_title) = state.album_title.clone() {
+        player_state.album_title = album_title;
+      }
+      if let Some(track_id) = state.track_id.clone() {
+        player_state.track_id = track_id;
+      }
+      if let Some(thumbnail) = &state.thumbnail {
+        player_state.thumbnail = thumbnail.thumbnail.to_owned();
+      }
+      if let Some(playback_status) = state.playback_status {
+        anchor_position(&mut player_state);
+        player_state.playback_status = playback_status;
+      }
+      if let Some(capabilities) = &state.capabilities {
+        // Stop button for MPRIS is tied to CanControl
+        if let Some(enabled) = capabilities.play_button_enabled {
+          player_state.can_play = enabled;
+        }
+        if let Some(enabled) = capabilities.pause_button_enabled {
+          player_state.can_pause = enabled;
+        }
+        if let Some(enabled) = capabilities.previous_button_enabled {
+          player_state.can_go_previous = enabled;
+        }
+        if let Some(enabled) = capabilities.next_button_enabled {
+          player_state.can_go_next = enabled;
+        }
+        if let Some(enabled) = capabilities.seek_enabled {
+          player_state.can_seek = enabled;
+        }
+      }
+    }
+
+    if state.title.is_some()
+      || state.artists.is_some()
+      || state.album_title.is_some()
+      || state.track_id.is_some()
+      || state.thumbnail.is_some()
+      || state.duration.is_some()
+    {
+      let metadata = self.construct_metadata();
+      self
+        .properties_changed
+        .add_prop("Metadata", EmitsChangedSignal::True, || metadata);
+    }
+    if let Some(playback_status) = state.playback_status {
+      self
+        .properties_changed
+        .add_prop(
+          "PlaybackStatus",
+          EmitsChangedSignal::True,
+          || match playback_status {
+            MediaPlayerPlaybackStatus::Playing => Box::new("Playing".to_string()),
+            MediaPlayerPlaybackStatus::Paused => Box::new("Paused".to_string()),
+            MediaPlayerPlaybackStatus::Stopped => Box::new("Stopped".to_string()),
+            _ => Box::new("Stopped".to_string()),
+          },
+        );
+    }
+    if let Some(capabilities) = state.capabilities {
+      for (name, enabled) in [
+        ("CanPlay", capabilities.play_button_enabled),
+        ("CanPause", capabilities.pause_button_enabled),
+        ("CanGoPrevious", capabilities.previous_button_enabled),
+        ("CanGoNext", capabilities.next_button_enabled),
+        ("CanSeek", capabilities.seek_enabled),
+      ] {
+        if let Some(enabled) = enabled {
+          self
+            .properties_changed
+            .add_prop(name, EmitsChangedSignal::True, || Box::new(enabled));
+        }
+      }
+    }
+    if let Some(position) = seeked {
+      self.dbus_session.emit_message(seeked_message(position));
+    }
 
     Ok(())
   }
@@ -374,51 +1574,227 @@ impl MediaPlayer {
 
   /// Sets the timeline data
   ///
-  /// You MUST call this function everytime the position changes in the song. The media service will become out of sync if this is not called enough or cause seeked signals to be emitted to the media service unnecessarily.
+  /// The position is advanced automatically while the playback status is playing, scaled by the playback rate and capped at the duration. You only need to call this function when the position changes in any other way, such as when the track changes. Seeks should be reported with seeked.
+  ///
+  /// A seeked signal is still emitted when the position differs from the expected position by more than the seek threshold, unless seek detection is turned off in the options. A change of duration is treated as a new track and never reported as a seek.
+  ///
+  /// Without options the options of the previous timeline are kept.
   #[napi]
   #[allow(dead_code)]
-  pub fn set_timeline(&mut self, duration: f64, position: f64) -> napi::Result<()> {
-    if duration < 0.0 {
-      return Err(napi::Error::from_reason("Duration cannot be less than 0"));
-    }
-    if position < 0.0 {
-      return Err(napi::Error::from_reason("Position cannot be less than 0"));
-    }
-    if position > duration {
-      return Err(napi::Error::from_reason(
-        "Position cannot be greather than provided duration",
-      ));
-    }
-
+  pub fn set_timeline(
+    &mut self,
+    duration: f64,
+    position: f64,
+    options: Option<MediaPlayerTimelineOptions>,
+  ) -> napi::Result<()> {
     if let Ok(mut player_state) = self.player_state.write() {
-      // If the position moved more than 1 second within 1 second of time then a seeked signal needs to be emitted
-      if position - player_state.position > player_state.playback_rate
-        && player_state.last_updated_position.elapsed().as_secs() < 1
+      let options = options.or_else(|| player_state.timeline_options.clone());
+      let bounds = validate_timeline(duration, position, &options)?;
+      let detect_seek = options
+        .as_ref()
+        .and_then(|options| options.detect_seek)
+        .unwrap_or(true);
+      let seek_threshold = options
+        .as_ref()
+        .and_then(|options| options.seek_threshold)
+        .unwrap_or(1.0);
+
+      if detect_seek
+        && player_state.duration == duration
+        && (position - current_position(&player_state)).abs() > seek_threshold
       {
-        let seeked = OrgMprisMediaPlayer2PlayerSeeked {
-          position: FloatDuration::seconds(position)
-            .as_microseconds()
-            .max(i64::MIN as f64)
-            .min(i64::MAX as f64)
-            .round() as i64,
-        };
-        self
-          .dbus_session
-          .emit_message(seeked.to_emit_message(&Path::new("/org/mpris/MediaPlayer2").unwrap()));
+        self.dbus_session.emit_message(seeked_message(position));
       }
 
+      // Only the duration is part of the metadata, position changes are never published as metadata
+      let duration_changed = player_state.duration != duration;
       player_state.duration = duration;
+      player_state.start_time = bounds.start_time;
+      player_state.min_seek_time = bounds.min_seek_time;
+      player_state.max_seek_time = bounds.max_seek_time;
+      player_state.timeline_options = options;
       player_state.position = position;
-      player_state.last_updated_position = Instant::now();
+      player_state.last_updated_position = player_state.clock.now();
+      player_state.ended = false;
 
       drop(player_state);
 
-      let metadata = self.construct_metadata();
-      self
-        .properties_changed
-        .add_prop("Metadata", EmitsChangedSignal::True, || metadata);
+      if duration_changed {
+        let metadata = self.construct_metadata();
+        self
+          .properties_changed
+          .add_prop("Metadata", EmitsChangedSignal::True, || metadata);
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Reports that a seek occurred and immediately emits a seeked signal with the new position in seconds
+  #[napi]
+  #[allow(dead_code)]
+  pub fn seeked(&mut self, position: f64) -> napi::Result<()> {
+    if let Ok(mut player_state) = self.player_state.write() {
+      if position.is_nan() || position < player_state.min_seek_time {
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_INVALID_TIMELINE,
+            "Position cannot be less than the min seek time",
+          )
+          .detail("position", position)
+          .detail("minSeekTime", player_state.min_seek_time)
+          .into(),
+        );
+      }
+      if player_state.duration > 0.0 && position > player_state.max_seek_time {
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_INVALID_TIMELINE,
+            "Position cannot be greater than the max seek time",
+          )
+          .detail("position", position)
+          .detail("maxSeekTime", player_state.max_seek_time)
+          .into(),
+        );
+      }
+
+      player_state.position = position;
+      player_state.last_updated_position = player_state.clock.now();
+      player_state.ended = false;
+    }
+
+    self.dbus_session.emit_message(seeked_message(position));
+
+    Ok(())
+  }
+
+  /// Gets the current position in seconds
+  ///
+  /// The position is extrapolated from the last timeline update while playing
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_position(&self) -> napi::Result<f64> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(current_position(&player_state));
+    }
+
+    Ok(0.0)
+  }
+
+  /// Gets the interval in seconds between 'tick' events
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_tick_interval(&self) -> napi::Result<Option<f64>> {
+    Ok(self.tick_interval)
+  }
+
+  /// Sets the interval in seconds between 'tick' events
+  ///
+  /// The 'tick' and 'ended' events are only emitted while a tick interval is set
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_tick_interval(&mut self, tick_interval: Option<f64>) -> napi::Result<()> {
+    let tick_duration = match tick_interval {
+      Some(tick_interval) => Some(
+        Duration::try_from_secs_f64(tick_interval)
+          .ok()
+          .filter(|tick_duration| !tick_duration.is_zero())
+          .ok_or_else(|| {
+            XosmsError::new(
+              XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+              format!(
+                "{} is not a valid tick interval, it must be a finite number greater than 0",
+                tick_interval
+              ),
+            )
+            .detail("value", tick_interval)
+          })?,
+      ),
+      None => None,
+    };
+
+    self.tick_interval = tick_interval;
+
+    // The ticker thread is started once and reconfigured by sending it the new interval
+    if let Some(tick_sender) = &self.tick_sender {
+      if tick_sender.send(tick_duration).is_ok() {
+        return Ok(());
+      }
+    }
+    if tick_duration.is_none() {
+      return Ok(());
+    }
+
+    let (tick_sender, tick_receiver) = mpsc::channel::<Option<Duration>>();
+    let player_state = self.player_state.clone();
+    let tick_listeners = self.tick_listeners.clone();
+    let ended_listeners = self.ended_listeners.clone();
+    let mut tick_duration = tick_duration;
+    thread::spawn(move || loop {
+      // Without an interval the thread waits for a new one, it stops once the media player is dropped
+      let received = match tick_duration {
+        Some(tick_duration) => tick_receiver.recv_timeout(tick_duration),
+        None => tick_receiver
+          .recv()
+          .map_err(|_| RecvTimeoutError::Disconnected),
+      };
+      match received {
+        Ok(new_tick_duration) => {
+          tick_duration = new_tick_duration;
+          continue;
+        }
+        Err(RecvTimeoutError::Disconnected) => return,
+        Err(RecvTimeoutError::Timeout) => {}
+      }
+
+      if let Ok(mut state) = player_state.write() {
+        if state.playback_status != MediaPlayerPlaybackStatus::Playing {
+          continue;
+        }
+
+        let position = current_position(&state);
+        for listener in tick_listeners.iter() {
+          listener.call(Ok(position), ThreadsafeFunctionCallMode::NonBlocking);
+        }
+
+        if !state.ended && state.duration > 0.0 && position >= state.duration {
+          state.ended = true;
+          for listener in ended_listeners.iter() {
+            listener.call(Ok(()), ThreadsafeFunctionCallMode::NonBlocking);
+          }
+        }
+      }
+    });
+    self.tick_sender = Some(tick_sender);
+
+    Ok(())
+  }
+
+  /// Advances the playback clock by the provided amount of seconds, only available with the mock backend
+  ///
+  /// The playback clock of the mock backend only moves when advanced
+  #[napi]
+  #[allow(dead_code)]
+  pub fn advance_clock(&mut self, seconds: f64) -> napi::Result<()> {
+    let Some(manual_clock) = &self.manual_clock else {
+      return Err(backend::mock_only("advanceClock").into());
+    };
+    if seconds.is_nan() || seconds < 0.0 {
+      return Err(
+        XosmsError::new(
+          XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+          format!(
+            "{} is not a valid amount of seconds to advance the clock by",
+            seconds
+          ),
+        )
+        .detail("value", seconds)
+        .into(),
+      );
     }
 
+    manual_clock.advance(seconds);
+
     Ok(())
   }
 
@@ -579,255 +1955,1764 @@ impl MediaPlayer {
     Ok(())
   }
 
-  /// Gets the playback rate
+  /// Gets the identity of the media player
   #[napi(getter)]
   #[allow(dead_code)]
-  pub fn get_playback_rate(&self) -> napi::Result<f64> {
+  pub fn get_identity(&self) -> napi::Result<String> {
     if let Ok(player_state) = self.player_state.read() {
-      return Ok(player_state.playback_rate);
+      return Ok(player_state.identity.to_owned());
     }
 
-    Ok(1.0)
+    Ok("".to_string())
   }
 
-  /// Sets the playback rate
+  /// Sets the identity of the media player
   #[napi(setter)]
   #[allow(dead_code)]
-  pub fn set_playback_rate(&mut self, playback_rate: f64) -> napi::Result<()> {
+  pub fn set_identity(&mut self, identity: String) -> napi::Result<()> {
     if let Ok(mut player_state) = self.player_state.write() {
-      player_state.playback_rate = playback_rate;
+      player_state.identity = identity.to_owned();
       drop(player_state);
 
       self
-        .properties_changed
-        .add_prop("Rate", EmitsChangedSignal::True, || Box::new(playback_rate));
+        .root_properties_changed
+        .add_prop("Identity", EmitsChangedSignal::True, || Box::new(identity));
     }
 
     Ok(())
   }
 
-  /// Gets the playback status
+  /// Gets the desktop entry name of the media player without the .desktop extension
   #[napi(getter)]
   #[allow(dead_code)]
-  pub fn get_playback_status(&self) -> napi::Result<MediaPlayerPlaybackStatus> {
+  pub fn get_desktop_entry(&self) -> napi::Result<String> {
     if let Ok(player_state) = self.player_state.read() {
-      return Ok(player_state.playback_status);
+      return Ok(player_state.desktop_entry.to_owned());
     }
 
-    Ok(MediaPlayerPlaybackStatus::Unknown)
+    Ok("".to_string())
   }
 
-  /// Sets the playback status
+  /// Sets the desktop entry name of the media player without the .desktop extension
   #[napi(setter)]
   #[allow(dead_code)]
-  pub fn set_playback_status(
-    &mut self,
-    playback_status: MediaPlayerPlaybackStatus,
-  ) -> napi::Result<()> {
-    if playback_status == MediaPlayerPlaybackStatus::Unknown {
-      return Err(napi::Error::from_reason(format!(
-        "{:?} is not a valid MediaPlayerPlaybackStatus to set",
-        playback_status
-      )));
-    }
-
+  pub fn set_desktop_entry(&mut self, desktop_entry: String) -> napi::Result<()> {
     if let Ok(mut player_state) = self.player_state.write() {
-      player_state.playback_status = playback_status;
+      player_state.desktop_entry = desktop_entry.to_owned();
       drop(player_state);
 
       self
-        .properties_changed
-        .add_prop(
-          "PlaybackStatus",
-          EmitsChangedSignal::True,
-          || match playback_status {
-            MediaPlayerPlaybackStatus::Playing => Box::new("Playing".to_string()),
-            MediaPlayerPlaybackStatus::Paused => Box::new("Paused".to_string()),
-            MediaPlayerPlaybackStatus::Stopped => Box::new("Stopped".to_string()),
-            _ => Box::new("Stopped".to_string()),
-          },
-        );
+        .root_properties_changed
+        .add_prop("DesktopEntry", EmitsChangedSignal::True, || {
+          Box::new(desktop_entry)
+        });
     }
 
     Ok(())
   }
 
-  /// Gets the media type
+  /// Gets the fullscreen state
   #[napi(getter)]
   #[allow(dead_code)]
-  pub fn get_media_type(&self) -> napi::Result<MediaPlayerMediaType> {
+  pub fn get_fullscreen(&self) -> napi::Result<bool> {
     if let Ok(player_state) = self.player_state.read() {
-      return Ok(player_state.media_type);
+      return Ok(player_state.fullscreen);
     }
 
-    Ok(MediaPlayerMediaType::Unknown)
+    Ok(false)
   }
 
-  /// Sets the media type
+  /// Sets the fullscreen state
   #[napi(setter)]
   #[allow(dead_code)]
-  pub fn set_media_type(&mut self, media_type: MediaPlayerMediaType) -> napi::Result<()> {
-    if media_type == MediaPlayerMediaType::Unknown {
-      return Err(napi::Error::from_reason(format!(
-        "{:?} is not a valid MediaPlayerMediaType to set",
-        media_type
-      )));
-    }
-
+  pub fn set_fullscreen(&mut self, enabled: bool) -> napi::Result<()> {
     if let Ok(mut player_state) = self.player_state.write() {
-      player_state.media_type = media_type;
+      player_state.fullscreen = enabled;
       drop(player_state);
+
+      self
+        .root_properties_changed
+        .add_prop("Fullscreen", EmitsChangedSignal::True, || Box::new(enabled));
     }
 
     Ok(())
   }
 
-  /// Gets the media title
+  /// Gets whether the fullscreen state can be set
   #[napi(getter)]
   #[allow(dead_code)]
-  pub fn get_title(&self) -> napi::Result<String> {
+  pub fn get_can_set_fullscreen(&self) -> napi::Result<bool> {
     if let Ok(player_state) = self.player_state.read() {
-      return Ok(player_state.title.to_owned());
+      return Ok(player_state.can_set_fullscreen);
     }
 
-    Ok("".to_string())
+    Ok(false)
   }
 
-  /// Sets the media title
+  /// Sets whether the fullscreen state can be set
   #[napi(setter)]
   #[allow(dead_code)]
-  pub fn set_title(&mut self, title: String) -> napi::Result<()> {
+  pub fn set_can_set_fullscreen(&mut self, enabled: bool) -> napi::Result<()> {
     if let Ok(mut player_state) = self.player_state.write() {
-      player_state.title = title;
+      player_state.can_set_fullscreen = enabled;
       drop(player_state);
 
-      let metadata = self.construct_metadata();
       self
-        .properties_changed
-        .add_prop("Metadata", EmitsChangedSignal::True, || metadata);
+        .root_properties_changed
+        .add_prop("CanSetFullscreen", EmitsChangedSignal::True, || {
+          Box::new(enabled)
+        });
     }
 
     Ok(())
   }
 
-  /// Gets the media artist
+  /// Gets the raise enabled state
   #[napi(getter)]
   #[allow(dead_code)]
-  pub fn get_artist(&self) -> napi::Result<String> {
+  pub fn get_raise_enabled(&self) -> napi::Result<bool> {
     if let Ok(player_state) = self.player_state.read() {
-      return Ok(player_state.artist.to_owned());
+      return Ok(player_state.can_raise);
     }
 
-    Ok("".to_string())
+    Ok(false)
   }
 
-  /// Sets the media artist
+  /// Sets the raise enabled state
   #[napi(setter)]
   #[allow(dead_code)]
-  pub fn set_artist(&mut self, artist: String) -> napi::Result<()> {
+  pub fn set_raise_enabled(&mut self, enabled: bool) -> napi::Result<()> {
     if let Ok(mut player_state) = self.player_state.write() {
-      player_state.artist = artist;
+      player_state.can_raise = enabled;
       drop(player_state);
 
-      let metadata = self.construct_metadata();
       self
-        .properties_changed
-        .add_prop("Metadata", EmitsChangedSignal::True, || metadata);
+        .root_properties_changed
+        .add_prop("CanRaise", EmitsChangedSignal::True, || Box::new(enabled));
     }
 
     Ok(())
   }
 
-  /// Gets the media album title
+  /// Gets the quit enabled state
   #[napi(getter)]
   #[allow(dead_code)]
-  pub fn get_album_title(&self) -> napi::Result<String> {
+  pub fn get_quit_enabled(&self) -> napi::Result<bool> {
     if let Ok(player_state) = self.player_state.read() {
-      return Ok(player_state.album_title.to_owned());
+      return Ok(player_state.can_quit);
     }
 
-    Ok("".to_string())
+    Ok(false)
   }
 
-  /// Sets the media artist
+  /// Sets the quit enabled state
   #[napi(setter)]
   #[allow(dead_code)]
-  pub fn set_album_title(&mut self, album_title: String) -> napi::Result<()> {
+  pub fn set_quit_enabled(&mut self, enabled: bool) -> napi::Result<()> {
     if let Ok(mut player_state) = self.player_state.write() {
-      player_state.album_title = album_title;
+      player_state.can_quit = enabled;
       drop(player_state);
 
-      let metadata = self.construct_metadata();
       self
-        .properties_changed
-        .add_prop("Metadata", EmitsChangedSignal::True, || metadata);
+        .root_properties_changed
+        .add_prop("CanQuit", EmitsChangedSignal::True, || Box::new(enabled));
     }
 
     Ok(())
   }
 
-  /// Gets the track id
+  /// Gets the uri schemes that can be opened
   #[napi(getter)]
   #[allow(dead_code)]
-  pub fn get_track_id(&self) -> napi::Result<String> {
+  pub fn get_supported_uri_schemes(&self) -> napi::Result<Vec<String>> {
     if let Ok(player_state) = self.player_state.read() {
-      return Ok(player_state.track_id.to_owned());
+      return Ok(player_state.supported_uri_schemes.clone());
     }
 
-    Ok("".to_string())
+    Ok(vec![])
   }
 
-  /// Sets the track id
+  /// Sets the uri schemes that can be opened
+  ///
+  /// Only uris with one of these schemes will be emitted through the 'openuri' event
   #[napi(setter)]
   #[allow(dead_code)]
-  pub fn set_track_id(&mut self, track_id: String) -> napi::Result<()> {
+  pub fn set_supported_uri_schemes(
+    &mut self,
+    supported_uri_schemes: Vec<String>,
+  ) -> napi::Result<()> {
+    let supported_uri_schemes: Vec<String> = supported_uri_schemes
+      .iter()
+      .map(|scheme| scheme.to_lowercase())
+      .collect();
+
     if let Ok(mut player_state) = self.player_state.write() {
-      player_state.track_id = track_id;
+      player_state.supported_uri_schemes = supported_uri_schemes.clone();
       drop(player_state);
 
-      let metadata = self.construct_metadata();
-      self
-        .properties_changed
-        .add_prop("Metadata", EmitsChangedSignal::True, || metadata);
+      self.root_properties_changed.add_prop(
+        "SupportedUriSchemes",
+        EmitsChangedSignal::True,
+        || Box::new(supported_uri_schemes),
+      );
     }
 
     Ok(())
   }
 
-  fn construct_metadata(&self) -> Box<PropMap> {
-    if let Ok(state) = self.player_state.read() {
-      let mut metadata = Box::new(PropMap::new());
-      metadata.insert(
-        "mpris:trackid".to_string(),
-        Variant(Box::new(format!("/xosms/trackid/{}", state.track_id))),
+  /// Gets the mime types that can be opened
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_supported_mime_types(&self) -> napi::Result<Vec<String>> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.supported_mime_types.clone());
+    }
+
+    Ok(vec![])
+  }
+
+  /// Sets the mime types that can be opened
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_supported_mime_types(
+    &mut self,
+    supported_mime_types: Vec<String>,
+  ) -> napi::Result<()> {
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.supported_mime_types = supported_mime_types.clone();
+      drop(player_state);
+
+      self
+        .root_properties_changed
+        .add_prop("SupportedMimeTypes", EmitsChangedSignal::True, || {
+          Box::new(supported_mime_types)
+        });
+    }
+
+    Ok(())
+  }
+
+  /// Gets the playback rate
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_playback_rate(&self) -> napi::Result<f64> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.playback_rate);
+    }
+
+    Ok(1.0)
+  }
+
+  /// Sets the playback rate
+  ///
+  /// The playback rate is clamped between the minimum and maximum playback rate
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_playback_rate(&mut self, playback_rate: f64) -> napi::Result<()> {
+    if playback_rate.is_nan() {
+      return Err(
+        XosmsError::new(
+          XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+          "Playback rate must be a number",
+        )
+        .detail("value", playback_rate)
+        .into(),
+      );
+    }
+
+    if let Ok(mut player_state) = self.player_state.write() {
+      let playback_rate = playback_rate.clamp(player_state.minimum_rate, player_state.maximum_rate);
+      anchor_position(&mut player_state);
+      player_state.playback_rate = playback_rate;
+      drop(player_state);
+
+      self
+        .properties_changed
+        .add_prop("Rate", EmitsChangedSignal::True, || Box::new(playback_rate));
+    }
+
+    Ok(())
+  }
+
+  /// Gets the minimum playback rate
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_minimum_playback_rate(&self) -> napi::Result<f64> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.minimum_rate);
+    }
+
+    Ok(1.0)
+  }
+
+  /// Sets the minimum playback rate
+  ///
+  /// The minimum playback rate must be greater than 0.0 and at most 1.0
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_minimum_playback_rate(&mut self, minimum_playback_rate: f64) -> napi::Result<()> {
+    validate_minimum_playback_rate(minimum_playback_rate)?;
+
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.minimum_rate = minimum_playback_rate;
+      drop(player_state);
+
+      self
+        .properties_changed
+        .add_prop("MinimumRate", EmitsChangedSignal::True, || {
+          Box::new(minimum_playback_rate)
+        });
+    }
+    self.clamp_playback_rate();
+
+    Ok(())
+  }
+
+  /// Gets the maximum playback rate
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_maximum_playback_rate(&self) -> napi::Result<f64> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.maximum_rate);
+    }
+
+    Ok(1.0)
+  }
+
+  /// Sets the maximum playback rate
+  ///
+  /// The maximum playback rate must be at least 1.0
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_maximum_playback_rate(&mut self, maximum_playback_rate: f64) -> napi::Result<()> {
+    validate_maximum_playback_rate(maximum_playback_rate)?;
+
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.maximum_rate = maximum_playback_rate;
+      drop(player_state);
+
+      self
+        .properties_changed
+        .add_prop("MaximumRate", EmitsChangedSignal::True, || {
+          Box::new(maximum_playback_rate)
+        });
+    }
+    self.clamp_playback_rate();
+
+    Ok(())
+  }
+
+  /// Keeps the published playback rate within changed playback rate bounds
+  fn clamp_playback_rate(&mut self) {
+    if let Ok(mut player_state) = self.player_state.write() {
+      let playback_rate = player_state
+        .playback_rate
+        .clamp(player_state.minimum_rate, player_state.maximum_rate);
+      if playback_rate == player_state.playback_rate {
+        return;
+      }
+      anchor_position(&mut player_state);
+      player_state.playback_rate = playback_rate;
+      drop(player_state);
+
+      self
+        .properties_changed
+        .add_prop("Rate", EmitsChangedSignal::True, || Box::new(playback_rate));
+    }
+  }
+
+  /// Gets the playback status
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_playback_status(&self) -> napi::Result<MediaPlayerPlaybackStatus> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.playback_status);
+    }
+
+    Ok(MediaPlayerPlaybackStatus::Unknown)
+  }
+
+  /// Sets the playback status
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_playback_status(
+    &mut self,
+    playback_status: MediaPlayerPlaybackStatus,
+  ) -> napi::Result<()> {
+    if playback_status == MediaPlayerPlaybackStatus::Unknown {
+      return Err(
+        XosmsError::new(
+          XosmsErrorCode::XOSMS_INVALID_ENUM,
+          format!(
+            "{:?} is not a valid MediaPlayerPlaybackStatus to set",
+            playback_status
+          ),
+        )
+        .detail("value", playback_status as i32)
+        .detail("enum", "MediaPlayerPlaybackStatus")
+        .into(),
+      );
+    }
+
+    if let Ok(mut player_state) = self.player_state.write() {
+      anchor_position(&mut player_state);
+      player_state.playback_status = playback_status;
+      drop(player_state);
+
+      self
+        .properties_changed
+        .add_prop(
+          "PlaybackStatus",
+          EmitsChangedSignal::True,
+          || match playback_status {
+            MediaPlayerPlaybackStatus::Playing => Box::new("Playing".to_string()),
+            MediaPlayerPlaybackStatus::Paused => Box::new("Paused".to_string()),
+            MediaPlayerPlaybackStatus::Stopped => Box::new("Stopped".to_string()),
+            _ => Box::new("Stopped".to_string()),
+          },
+        );
+    }
+
+    Ok(())
+  }
+
+  /// Gets the repeat mode
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_repeat_mode(&self) -> napi::Result<MediaPlayerRepeatMode> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.repeat_mode);
+    }
+
+    Ok(MediaPlayerRepeatMode::Unknown)
+  }
+
+  /// Sets the repeat mode
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_repeat_mode(&mut self, repeat_mode: MediaPlayerRepeatMode) -> napi::Result<()> {
+    if repeat_mode == MediaPlayerRepeatMode::Unknown {
+      return Err(
+        XosmsError::new(
+          XosmsErrorCode::XOSMS_INVALID_ENUM,
+          format!(
+            "{:?} is not a valid MediaPlayerRepeatMode to set",
+            repeat_mode
+          ),
+        )
+        .detail("value", repeat_mode as i32)
+        .detail("enum", "MediaPlayerRepeatMode")
+        .into(),
+      );
+    }
+
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.repeat_mode = repeat_mode;
+      drop(player_state);
+
+      self
+        .properties_changed
+        .add_prop("LoopStatus", EmitsChangedSignal::True, || {
+          Box::new(repeat_mode_to_loop_status(repeat_mode).to_string())
+        });
+    }
+
+    Ok(())
+  }
+
+  /// Gets the shuffle state
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_shuffle(&self) -> napi::Result<bool> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.shuffle);
+    }
+
+    Ok(false)
+  }
+
+  /// Sets the shuffle state
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_shuffle(&mut self, shuffle: bool) -> napi::Result<()> {
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.shuffle = shuffle;
+      drop(player_state);
+
+      self
+        .properties_changed
+        .add_prop("Shuffle", EmitsChangedSignal::True, || Box::new(shuffle));
+    }
+
+    Ok(())
+  }
+
+  /// Gets the volume
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_volume(&self) -> napi::Result<f64> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.volume);
+    }
+
+    Ok(1.0)
+  }
+
+  /// Sets the volume
+  ///
+  /// The volume is clamped between 0.0 and 1.0
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_volume(&mut self, volume: f64) -> napi::Result<()> {
+    if volume.is_nan() {
+      return Err(
+        XosmsError::new(
+          XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+          "Volume must be a number",
+        )
+        .detail("value", volume)
+        .into(),
+      );
+    }
+
+    let volume = clamp_volume(volume);
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.volume = volume;
+      drop(player_state);
+
+      self
+        .properties_changed
+        .add_prop("Volume", EmitsChangedSignal::True, || Box::new(volume));
+    }
+
+    Ok(())
+  }
+
+  /// Gets the media type
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_media_type(&self) -> napi::Result<MediaPlayerMediaType> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.media_type);
+    }
+
+    Ok(MediaPlayerMediaType::Unknown)
+  }
+
+  /// Sets the media type
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_media_type(&mut self, media_type: MediaPlayerMediaType) -> napi::Result<()> {
+    if media_type == MediaPlayerMediaType::Unknown {
+      return Err(
+        XosmsError::new(
+          XosmsErrorCode::XOSMS_INVALID_ENUM,
+          format!(
+            "{:?} is not a valid MediaPlayerMediaType to set",
+            media_type
+          ),
+        )
+        .detail("value", media_type as i32)
+        .detail("enum", "MediaPlayerMediaType")
+        .into(),
+      );
+    }
+
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.media_type = media_type;
+      drop(player_state);
+    }
+
+    Ok(())
+  }
+
+  /// Gets the media title
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_title(&self) -> napi::Result<String> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.title.to_owned());
+    }
+
+    Ok("".to_string())
+  }
+
+  /// Sets the media title
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_title(&mut self, title: String) -> napi::Result<()> {
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.title = title;
+      drop(player_state);
+
+      let metadata = self.construct_metadata();
+      self
+        .properties_changed
+        .add_prop("Metadata", EmitsChangedSignal::True, || metadata);
+    }
+
+    Ok(())
+  }
+
+  /// Gets the media artist
+  ///
+  /// Returns the first of the media artists
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_artist(&self) -> napi::Result<String> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.artists.first().cloned().unwrap_or_default());
+    }
+
+    Ok("".to_string())
+  }
+
+  /// Sets the media artist
+  ///
+  /// Replaces all of the media artists with the provided artist
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_artist(&mut self, artist: String) -> napi::Result<()> {
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.artists = if artist.is_empty() {
+        vec![]
+      } else {
+        vec![artist]
+      };
+      drop(player_state);
+
+      let metadata = self.construct_metadata();
+      self
+        .properties_changed
+        .add_prop("Metadata", EmitsChangedSignal::True, || metadata);
+    }
+
+    Ok(())
+  }
+
+  /// Gets the media artists
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_artists(&self) -> napi::Result<Vec<String>> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.artists.to_owned());
+    }
+
+    Ok(vec![])
+  }
+
+  /// Sets the media artists
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_artists(&mut self, artists: Vec<String>) -> napi::Result<()> {
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.artists = artists;
+      drop(player_state);
+
+      let metadata = self.construct_metadata();
+      self
+        .properties_changed
+        .add_prop("Metadata", EmitsChangedSignal::True, || metadata);
+    }
+
+    Ok(())
+  }
+
+  /// Gets the media album artists
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_album_artists(&self) -> napi::Result<Vec<String>> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.album_artists.to_owned());
+    }
+
+    Ok(vec![])
+  }
+
+  /// Sets the media album artists
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_album_artists(&mut self, album_artists: Vec<String>) -> napi::Result<()> {
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.album_artists = album_artists;
+      drop(player_state);
+
+      let metadata = self.construct_metadata();
+      self
+        .properties_changed
+        .add_prop("Metadata", EmitsChangedSignal::True, || metadata);
+    }
+
+    Ok(())
+  }
+
+  /// Gets the media album title
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_album_title(&self) -> napi::Result<String> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.album_title.to_owned());
+    }
+
+    Ok("".to_string())
+  }
+
+  /// Sets the media artist
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_album_title(&mut self, album_title: String) -> napi::Result<()> {
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.album_title = album_title;
+      drop(player_state);
+
+      let metadata = self.construct_metadata();
+      self
+        .properties_changed
+        .add_prop("Metadata", EmitsChangedSignal::True, || metadata);
+    }
+
+    Ok(())
+  }
+
+  /// Gets the media genres
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_genres(&self) -> napi::Result<Vec<String>> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.genres.to_owned());
+    }
+
+    Ok(vec![])
+  }
+
+  /// Sets the media genres
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_genres(&mut self, genres: Vec<String>) -> napi::Result<()> {
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.genres = genres;
+      drop(player_state);
+
+      let metadata = self.construct_metadata();
+      self
+        .properties_changed
+        .add_prop("Metadata", EmitsChangedSignal::True, || metadata);
+    }
+
+    Ok(())
+  }
+
+  /// Gets the media composers
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_composers(&self) -> napi::Result<Vec<String>> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.composers.to_owned());
+    }
+
+    Ok(vec![])
+  }
+
+  /// Sets the media composers
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_composers(&mut self, composers: Vec<String>) -> napi::Result<()> {
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.composers = composers;
+      drop(player_state);
+
+      let metadata = self.construct_metadata();
+      self
+        .properties_changed
+        .add_prop("Metadata", EmitsChangedSignal::True, || metadata);
+    }
+
+    Ok(())
+  }
+
+  /// Gets the media lyricists
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_lyricists(&self) -> napi::Result<Vec<String>> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.lyricists.to_owned());
+    }
+
+    Ok(vec![])
+  }
+
+  /// Sets the media lyricists
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_lyricists(&mut self, lyricists: Vec<String>) -> napi::Result<()> {
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.lyricists = lyricists;
+      drop(player_state);
+
+      let metadata = self.construct_metadata();
+      self
+        .properties_changed
+        .add_prop("Metadata", EmitsChangedSignal::True, || metadata);
+    }
+
+    Ok(())
+  }
+
+  /// Gets the media comments
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_comments(&self) -> napi::Result<Vec<String>> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.comments.to_owned());
+    }
+
+    Ok(vec![])
+  }
+
+  /// Sets the media comments
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_comments(&mut self, comments: Vec<String>) -> napi::Result<()> {
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.comments = comments;
+      drop(player_state);
+
+      let metadata = self.construct_metadata();
+      self
+        .properties_changed
+        .add_prop("Metadata", EmitsChangedSignal::True, || metadata);
+    }
+
+    Ok(())
+  }
+
+  /// Gets the media track number
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_track_number(&self) -> napi::Result<Option<i32>> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.track_number);
+    }
+
+    Ok(None)
+  }
+
+  /// Sets the media track number
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_track_number(&mut self, track_number: Option<i32>) -> napi::Result<()> {
+    if let Some(track_number) = track_number {
+      if track_number < 0 {
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+            format!(
+              "{} is not a valid track number, it must be at least 0",
+              track_number
+            ),
+          )
+          .detail("value", track_number)
+          .into(),
+        );
+      }
+    }
+
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.track_number = track_number;
+      drop(player_state);
+
+      let metadata = self.construct_metadata();
+      self
+        .properties_changed
+        .add_prop("Metadata", EmitsChangedSignal::True, || metadata);
+    }
+
+    Ok(())
+  }
+
+  /// Gets the media disc number
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_disc_number(&self) -> napi::Result<Option<i32>> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.disc_number);
+    }
+
+    Ok(None)
+  }
+
+  /// Sets the media disc number
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_disc_number(&mut self, disc_number: Option<i32>) -> napi::Result<()> {
+    if let Some(disc_number) = disc_number {
+      if disc_number < 0 {
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+            format!(
+              "{} is not a valid disc number, it must be at least 0",
+              disc_number
+            ),
+          )
+          .detail("value", disc_number)
+          .into(),
+        );
+      }
+    }
+
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.disc_number = disc_number;
+      drop(player_state);
+
+      let metadata = self.construct_metadata();
+      self
+        .properties_changed
+        .add_prop("Metadata", EmitsChangedSignal::True, || metadata);
+    }
+
+    Ok(())
+  }
+
+  /// Gets the media user rating
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_user_rating(&self) -> napi::Result<Option<f64>> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.user_rating);
+    }
+
+    Ok(None)
+  }
+
+  /// Sets the media user rating
+  ///
+  /// The user rating must be between 0.0 and 1.0
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_user_rating(&mut self, user_rating: Option<f64>) -> napi::Result<()> {
+    if let Some(user_rating) = user_rating {
+      if user_rating.is_nan() || !(0.0..=1.0).contains(&user_rating) {
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+            format!(
+              "{} is not a valid user rating, it must be between 0.0 and 1.0",
+              user_rating
+            ),
+          )
+          .detail("value", user_rating)
+          .into(),
+        );
+      }
+    }
+
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.user_rating = user_rating;
+      drop(player_state);
+
+      let metadata = self.construct_metadata();
+      self
+        .properties_changed
+        .add_prop("Metadata", EmitsChangedSignal::True, || metadata);
+    }
+
+    Ok(())
+  }
+
+  /// Gets the media use count
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_use_count(&self) -> napi::Result<Option<i32>> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.use_count);
+    }
+
+    Ok(None)
+  }
+
+  /// Sets the media use count
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_use_count(&mut self, use_count: Option<i32>) -> napi::Result<()> {
+    if let Some(use_count) = use_count {
+      if use_count < 0 {
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+            format!(
+              "{} is not a valid use count, it must be at least 0",
+              use_count
+            ),
+          )
+          .detail("value", use_count)
+          .into(),
+        );
+      }
+    }
+
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.use_count = use_count;
+      drop(player_state);
+
+      let metadata = self.construct_metadata();
+      self
+        .properties_changed
+        .add_prop("Metadata", EmitsChangedSignal::True, || metadata);
+    }
+
+    Ok(())
+  }
+
+  /// Gets the media url
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_url(&self) -> napi::Result<String> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.url.to_owned());
+    }
+
+    Ok("".to_string())
+  }
+
+  /// Sets the media url
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_url(&mut self, url: String) -> napi::Result<()> {
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.url = url;
+      drop(player_state);
+
+      let metadata = self.construct_metadata();
+      self
+        .properties_changed
+        .add_prop("Metadata", EmitsChangedSignal::True, || metadata);
+    }
+
+    Ok(())
+  }
+
+  /// Gets the media content created date
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_content_created(&self) -> napi::Result<Option<f64>> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.content_created);
+    }
+
+    Ok(None)
+  }
+
+  /// Sets the media content created date
+  ///
+  /// The date is in milliseconds since the unix epoch
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_content_created(&mut self, content_created: Option<f64>) -> napi::Result<()> {
+    if let Some(content_created) = content_created {
+      if !content_created.is_finite() {
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+            format!("{} is not a valid content created date", content_created),
+          )
+          .detail("value", content_created)
+          .into(),
+        );
+      }
+    }
+
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.content_created = content_created;
+      drop(player_state);
+
+      let metadata = self.construct_metadata();
+      self
+        .properties_changed
+        .add_prop("Metadata", EmitsChangedSignal::True, || metadata);
+    }
+
+    Ok(())
+  }
+
+  /// Gets the media first used date
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_first_used(&self) -> napi::Result<Option<f64>> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.first_used);
+    }
+
+    Ok(None)
+  }
+
+  /// Sets the media first used date
+  ///
+  /// The date is in milliseconds since the unix epoch
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_first_used(&mut self, first_used: Option<f64>) -> napi::Result<()> {
+    if let Some(first_used) = first_used {
+      if !first_used.is_finite() {
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+            format!("{} is not a valid first used date", first_used),
+          )
+          .detail("value", first_used)
+          .into(),
+        );
+      }
+    }
+
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.first_used = first_used;
+      drop(player_state);
+
+      let metadata = self.construct_metadata();
+      self
+        .properties_changed
+        .add_prop("Metadata", EmitsChangedSignal::True, || metadata);
+    }
+
+    Ok(())
+  }
+
+  /// Gets the media last used date
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_last_used(&self) -> napi::Result<Option<f64>> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.last_used);
+    }
+
+    Ok(None)
+  }
+
+  /// Sets the media last used date
+  ///
+  /// The date is in milliseconds since the unix epoch
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_last_used(&mut self, last_used: Option<f64>) -> napi::Result<()> {
+    if let Some(last_used) = last_used {
+      if !last_used.is_finite() {
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+            format!("{} is not a valid last used date", last_used),
+          )
+          .detail("value", last_used)
+          .into(),
+        );
+      }
+    }
+
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.last_used = last_used;
+      drop(player_state);
+
+      let metadata = self.construct_metadata();
+      self
+        .properties_changed
+        .add_prop("Metadata", EmitsChangedSignal::True, || metadata);
+    }
+
+    Ok(())
+  }
+
+  /// Sets a custom metadata entry which is published alongside the rest of the metadata
+  ///
+  /// The key must be in the form of namespace:name, keys in the mpris namespace and xesam keys set through properties such as xesam:artist are reserved
+  ///
+  /// Integral numbers are published as 64-bit integers, other numbers as doubles and dates as ISO 8601 strings
+  #[napi]
+  #[allow(dead_code)]
+  pub fn set_custom_metadata(
+    &mut self,
+    key: String,
+    #[napi(ts_arg_type = "string | number | boolean | Array<string> | Date")] value: JsUnknown,
+  ) -> napi::Result<()> {
+    validate_custom_metadata_key(&key)?;
+    let value = js_to_custom_metadata_value(value)?;
+
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.custom_metadata.insert(key, value);
+      drop(player_state);
+
+      let metadata = self.construct_metadata();
+      self
+        .properties_changed
+        .add_prop("Metadata", EmitsChangedSignal::True, || metadata);
+    }
+
+    Ok(())
+  }
+
+  /// Removes a custom metadata entry
+  #[napi]
+  #[allow(dead_code)]
+  pub fn remove_custom_metadata(&mut self, key: String) -> napi::Result<()> {
+    if let Ok(mut player_state) = self.player_state.write() {
+      if player_state.custom_metadata.remove(&key).is_none() {
+        return Ok(());
+      }
+      drop(player_state);
+
+      let metadata = self.construct_metadata();
+      self
+        .properties_changed
+        .add_prop("Metadata", EmitsChangedSignal::True, || metadata);
+    }
+
+    Ok(())
+  }
+
+  /// Gets the track id
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_track_id(&self) -> napi::Result<String> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.track_id.to_owned());
+    }
+
+    Ok("".to_string())
+  }
+
+  /// Sets the track id
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_track_id(&mut self, track_id: String) -> napi::Result<()> {
+    if !track_id.is_empty() {
+      track_id_to_path(&track_id)?;
+    }
+
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.track_id = track_id;
+      drop(player_state);
+
+      let metadata = self.construct_metadata();
+      self
+        .properties_changed
+        .add_prop("Metadata", EmitsChangedSignal::True, || metadata);
+    }
+
+    Ok(())
+  }
+
+  /// Gets the track list
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_track_list(&self) -> napi::Result<Vec<MediaPlayerTrack>> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.track_list.to_owned());
+    }
+
+    Ok(vec![])
+  }
+
+  /// Replaces the track list with the provided tracks
+  ///
+  /// The current track id should be the id of the track currently playing, if any, and must be in the provided tracks
+  #[napi]
+  #[allow(dead_code)]
+  pub fn set_track_list(
+    &mut self,
+    tracks: Vec<MediaPlayerTrack>,
+    current_track_id: Option<String>,
+  ) -> napi::Result<()> {
+    let mut track_paths = vec![];
+    for track in tracks.iter() {
+      let track_path = track_id_to_path(&track.track_id)?;
+      if track_paths.contains(&track_path) {
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_DUPLICATE_ID,
+            format!("{} is in the track list more than once", track.track_id),
+          )
+          .detail("value", track.track_id.to_owned())
+          .into(),
+        );
+      }
+      track_paths.push(track_path);
+    }
+    let current_track = match current_track_id {
+      Some(current_track_id) => {
+        let current_track = track_id_to_path(&current_track_id)?;
+        if !track_paths.contains(&current_track) {
+          return Err(
+            XosmsError::new(
+              XosmsErrorCode::XOSMS_NOT_FOUND,
+              format!("{} is not in the track list", current_track_id),
+            )
+            .detail("value", current_track_id.to_owned())
+            .into(),
+          );
+        }
+        current_track
+      }
+      None => Path::new(NO_TRACK_PATH).unwrap(),
+    };
+
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.track_list = tracks;
+      drop(player_state);
+
+      let track_list_replaced = OrgMprisMediaPlayer2TrackListTrackListReplaced {
+        tracks: track_paths,
+        current_track,
+      };
+      self.dbus_session.emit_message(
+        track_list_replaced.to_emit_message(&Path::new("/org/mpris/MediaPlayer2").unwrap()),
+      );
+      self.track_list_properties_changed.invalidate("Tracks");
+    }
+
+    Ok(())
+  }
+
+  /// Adds a track to the track list
+  ///
+  /// The track is inserted after the track with the provided id or at the start of the track list when no id is provided
+  #[napi]
+  #[allow(dead_code)]
+  pub fn add_track(
+    &mut self,
+    track: MediaPlayerTrack,
+    after_track_id: Option<String>,
+  ) -> napi::Result<()> {
+    let track_path = track_id_to_path(&track.track_id)?;
+
+    if let Ok(mut player_state) = self.player_state.write() {
+      if player_state
+        .track_list
+        .iter()
+        .any(|t| t.track_id == track.track_id)
+      {
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_DUPLICATE_ID,
+            format!("{} is already in the track list", track.track_id),
+          )
+          .detail("value", track.track_id.to_owned())
+          .into(),
+        );
+      }
+      let (index, after_track) = match after_track_id {
+        Some(after_track_id) => {
+          let index = player_state
+            .track_list
+            .iter()
+            .position(|t| t.track_id == after_track_id);
+          match index {
+            Some(index) => (index + 1, track_id_to_path(&after_track_id)?),
+            None => {
+              return Err(
+                XosmsError::new(
+                  XosmsErrorCode::XOSMS_NOT_FOUND,
+                  format!("{} is not in the track list", after_track_id),
+                )
+                .detail("value", after_track_id.to_owned())
+                .into(),
+              )
+            }
+          }
+        }
+        None => (0, Path::new(NO_TRACK_PATH).unwrap()),
+      };
+      let metadata = construct_track_metadata(&track_path, &track);
+      player_state.track_list.insert(index, track);
+      drop(player_state);
+
+      let track_added = OrgMprisMediaPlayer2TrackListTrackAdded {
+        metadata,
+        after_track,
+      };
+      self
+        .dbus_session
+        .emit_message(track_added.to_emit_message(&Path::new("/org/mpris/MediaPlayer2").unwrap()));
+      self.track_list_properties_changed.invalidate("Tracks");
+    }
+
+    Ok(())
+  }
+
+  /// Removes a track from the track list
+  #[napi]
+  #[allow(dead_code)]
+  pub fn remove_track(&mut self, track_id: String) -> napi::Result<()> {
+    let track_path = track_id_to_path(&track_id)?;
+
+    if let Ok(mut player_state) = self.player_state.write() {
+      let index = player_state
+        .track_list
+        .iter()
+        .position(|t| t.track_id == track_id);
+      match index {
+        Some(index) => {
+          player_state.track_list.remove(index);
+        }
+        None => {
+          return Err(
+            XosmsError::new(
+              XosmsErrorCode::XOSMS_NOT_FOUND,
+              format!("{} is not in the track list", track_id),
+            )
+            .detail("value", track_id.to_owned())
+            .into(),
+          )
+        }
+      }
+      drop(player_state);
+
+      let track_removed = OrgMprisMediaPlayer2TrackListTrackRemoved {
+        track_id: track_path,
+      };
+      self.dbus_session.emit_message(
+        track_removed.to_emit_message(&Path::new("/org/mpris/MediaPlayer2").unwrap()),
+      );
+      self.track_list_properties_changed.invalidate("Tracks");
+    }
+
+    Ok(())
+  }
+
+  /// Updates the metadata of a track in the track list
+  #[napi]
+  #[allow(dead_code)]
+  pub fn update_track(&mut self, track: MediaPlayerTrack) -> napi::Result<()> {
+    let track_path = track_id_to_path(&track.track_id)?;
+
+    if let Ok(mut player_state) = self.player_state.write() {
+      let index = player_state
+        .track_list
+        .iter()
+        .position(|t| t.track_id == track.track_id);
+      let metadata = construct_track_metadata(&track_path, &track);
+      match index {
+        Some(index) => {
+          player_state.track_list[index] = track;
+        }
+        None => {
+          return Err(
+            XosmsError::new(
+              XosmsErrorCode::XOSMS_NOT_FOUND,
+              format!("{} is not in the track list", track.track_id),
+            )
+            .detail("value", track.track_id.to_owned())
+            .into(),
+          )
+        }
+      }
+      drop(player_state);
+
+      let track_metadata_changed = OrgMprisMediaPlayer2TrackListTrackMetadataChanged {
+        track_id: track_path,
+        metadata,
+      };
+      self.dbus_session.emit_message(
+        track_metadata_changed.to_emit_message(&Path::new("/org/mpris/MediaPlayer2").unwrap()),
       );
-      metadata.insert(
-        "mpris:length".to_string(),
-        Variant(Box::new(
-          FloatDuration::seconds(state.position)
-            .as_microseconds()
-            .max(i64::MIN as f64)
-            .min(i64::MAX as f64)
-            .round() as i64,
-        )),
+    }
+
+    Ok(())
+  }
+
+  /// Gets the track list editable state
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_can_edit_tracks(&self) -> napi::Result<bool> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.can_edit_tracks);
+    }
+
+    Ok(false)
+  }
+
+  /// Sets the track list editable state
+  ///
+  /// When disabled the media service cannot request tracks to be added or removed
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_can_edit_tracks(&mut self, enabled: bool) -> napi::Result<()> {
+    if let Ok(mut player_state) = self.player_state.write() {
+      player_state.can_edit_tracks = enabled;
+      drop(player_state);
+
+      self.track_list_properties_changed.add_prop(
+        "CanEditTracks",
+        EmitsChangedSignal::True,
+        || Box::new(enabled),
       );
-      metadata.insert(
-        "mpris:artUrl".to_string(),
-        Variant(Box::new(state.thumbnail.to_owned())),
+    }
+
+    Ok(())
+  }
+
+  /// Gets the playlists
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_playlists(&self) -> napi::Result<Vec<MediaPlayerPlaylist>> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.playlists.to_owned());
+    }
+
+    Ok(vec![])
+  }
+
+  /// Replaces the playlists with the provided playlists
+  ///
+  /// The order of the provided playlists is used as the user defined ordering
+  #[napi]
+  #[allow(dead_code)]
+  pub fn set_playlists(&mut self, playlists: Vec<MediaPlayerPlaylist>) -> napi::Result<()> {
+    let mut playlist_paths = vec![];
+    for playlist in playlists.iter() {
+      let playlist_path = playlist_id_to_path(&playlist.playlist_id)?;
+      if playlist_paths.contains(&playlist_path) {
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_DUPLICATE_ID,
+            format!(
+              "{} is in the playlists more than once",
+              playlist.playlist_id
+            ),
+          )
+          .detail("value", playlist.playlist_id.to_owned())
+          .into(),
+        );
+      }
+      playlist_paths.push(playlist_path);
+    }
+
+    if let Ok(mut player_state) = self.player_state.write() {
+      let mut changed_playlists = vec![];
+      for playlist in playlists.iter() {
+        let existing_playlist = player_state
+          .playlists
+          .iter()
+          .find(|p| p.playlist_id == playlist.playlist_id);
+        if let Some(existing_playlist) = existing_playlist {
+          if existing_playlist.name != playlist.name || existing_playlist.icon != playlist.icon {
+            changed_playlists.push(construct_playlist(playlist));
+          }
+        }
+      }
+      let playlist_count = playlists.len() as u32;
+      player_state.playlists = playlists;
+      let active_playlist_removed = match &player_state.active_playlist_id {
+        Some(active_playlist_id) => !player_state
+          .playlists
+          .iter()
+          .any(|p| &p.playlist_id == active_playlist_id),
+        None => false,
+      };
+      if active_playlist_removed {
+        player_state.active_playlist_id = None;
+      }
+      let active_playlist = construct_active_playlist(&player_state);
+      drop(player_state);
+
+      for playlist in changed_playlists.into_iter().flatten() {
+        let playlist_changed = OrgMprisMediaPlayer2PlaylistsPlaylistChanged { playlist };
+        self.dbus_session.emit_message(
+          playlist_changed.to_emit_message(&Path::new("/org/mpris/MediaPlayer2").unwrap()),
+        );
+      }
+      self
+        .playlists_properties_changed
+        .add_prop("PlaylistCount", EmitsChangedSignal::True, || {
+          Box::new(playlist_count)
+        });
+      self.playlists_properties_changed.add_prop(
+        "ActivePlaylist",
+        EmitsChangedSignal::True,
+        || Box::new(active_playlist),
       );
-      metadata.insert(
-        "xesam:title".to_string(),
-        Variant(Box::new(state.title.to_owned())),
+    }
+
+    Ok(())
+  }
+
+  /// Updates a playlist
+  ///
+  /// The media service is notified when the name or icon of the playlist changes
+  #[napi]
+  #[allow(dead_code)]
+  pub fn update_playlist(&mut self, playlist: MediaPlayerPlaylist) -> napi::Result<()> {
+    if let Ok(mut player_state) = self.player_state.write() {
+      let index = player_state
+        .playlists
+        .iter()
+        .position(|p| p.playlist_id == playlist.playlist_id);
+      let index = match index {
+        Some(index) => index,
+        None => {
+          return Err(
+            XosmsError::new(
+              XosmsErrorCode::XOSMS_NOT_FOUND,
+              format!("{} is not in the playlists", playlist.playlist_id),
+            )
+            .detail("value", playlist.playlist_id.to_owned())
+            .into(),
+          )
+        }
+      };
+      let existing_playlist = &player_state.playlists[index];
+      let changed =
+        existing_playlist.name != playlist.name || existing_playlist.icon != playlist.icon;
+      let is_active = player_state.active_playlist_id.as_ref() == Some(&playlist.playlist_id);
+      let changed_playlist = construct_playlist(&playlist);
+      player_state.playlists[index] = playlist;
+      let active_playlist = construct_active_playlist(&player_state);
+      drop(player_state);
+
+      if !changed {
+        return Ok(());
+      }
+      if let Some(playlist) = changed_playlist {
+        let playlist_changed = OrgMprisMediaPlayer2PlaylistsPlaylistChanged { playlist };
+        self.dbus_session.emit_message(
+          playlist_changed.to_emit_message(&Path::new("/org/mpris/MediaPlayer2").unwrap()),
+        );
+      }
+      if is_active {
+        self.playlists_properties_changed.add_prop(
+          "ActivePlaylist",
+          EmitsChangedSignal::True,
+          || Box::new(active_playlist),
+        );
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Gets the active playlist id
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_active_playlist_id(&self) -> napi::Result<Option<String>> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.active_playlist_id.to_owned());
+    }
+
+    Ok(None)
+  }
+
+  /// Sets the active playlist id
+  ///
+  /// The playlist must be in the playlists, an empty value means no playlist is active
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_active_playlist_id(&mut self, playlist_id: Option<String>) -> napi::Result<()> {
+    if let Ok(mut player_state) = self.player_state.write() {
+      if let Some(playlist_id) = &playlist_id {
+        if !player_state
+          .playlists
+          .iter()
+          .any(|p| &p.playlist_id == playlist_id)
+        {
+          return Err(
+            XosmsError::new(
+              XosmsErrorCode::XOSMS_NOT_FOUND,
+              format!("{} is not in the playlists", playlist_id),
+            )
+            .detail("value", playlist_id.to_owned())
+            .into(),
+          );
+        }
+      }
+      player_state.active_playlist_id = playlist_id;
+      let active_playlist = construct_active_playlist(&player_state);
+      drop(player_state);
+
+      self.playlists_properties_changed.add_prop(
+        "ActivePlaylist",
+        EmitsChangedSignal::True,
+        || Box::new(active_playlist),
       );
-      metadata.insert(
-        "xesam:album".to_string(),
-        Variant(Box::new(state.album_title.to_owned())),
+    }
+
+    Ok(())
+  }
+
+  /// Gets the supported playlist orderings
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_playlist_orderings(&self) -> napi::Result<Vec<MediaPlayerPlaylistOrdering>> {
+    if let Ok(player_state) = self.player_state.read() {
+      return Ok(player_state.playlist_orderings.to_owned());
+    }
+
+    Ok(vec![])
+  }
+
+  /// Sets the supported playlist orderings
+  ///
+  /// At least one ordering must be supported
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_playlist_orderings(
+    &mut self,
+    playlist_orderings: Vec<MediaPlayerPlaylistOrdering>,
+  ) -> napi::Result<()> {
+    if playlist_orderings.is_empty() {
+      return Err(
+        XosmsError::new(
+          XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+          "At least one MediaPlayerPlaylistOrdering must be set",
+        )
+        .into(),
       );
-      metadata.insert(
-        "xesam:artist".to_string(),
-        Variant(Box::new(state.artist.to_owned())),
+    }
+    if let Some(playlist_ordering) = playlist_orderings
+      .iter()
+      .find(|o| **o == MediaPlayerPlaylistOrdering::Unknown)
+    {
+      return Err(
+        XosmsError::new(
+          XosmsErrorCode::XOSMS_INVALID_ENUM,
+          format!(
+            "{:?} is not a valid MediaPlayerPlaylistOrdering to set",
+            playlist_ordering
+          ),
+        )
+        .detail("value", *playlist_ordering as i32)
+        .detail("enum", "MediaPlayerPlaylistOrdering")
+        .into(),
       );
-      return metadata;
+    }
+
+    if let Ok(mut player_state) = self.player_state.write() {
+      let orderings: Vec<String> = playlist_orderings
+        .iter()
+        .map(|o| playlist_ordering_to_string(*o).to_string())
+        .collect();
+      player_state.playlist_orderings = playlist_orderings;
+      drop(player_state);
+
+      self
+        .playlists_properties_changed
+        .add_prop("Orderings", EmitsChangedSignal::True, || {
+          Box::new(orderings)
+        });
+    }
+
+    Ok(())
+  }
+
+  fn properties_changed_queues(&self) -> Vec<PropertiesChangedQueue> {
+    vec![
+      self.root_properties_changed.clone(),
+      self.properties_changed.clone(),
+      self.track_list_properties_changed.clone(),
+      self.playlists_properties_changed.clone(),
+    ]
+  }
+
+  fn construct_metadata(&self) -> Box<PropMap> {
+    if let Ok(state) = self.player_state.read() {
+      return Box::new(construct_metadata(&state));
     }
 
     Box::new(PropMap::new())
@@ -836,31 +3721,877 @@ impl MediaPlayer {
 
 impl ObjectFinalize for MediaPlayer {
   fn finalize(self, _env: napi::Env) -> napi::Result<()> {
-    self.dbus_session.unregister(&self.service_name);
+    // The response is dropped so the JS thread does not wait on the session bus
+    self.dbus_session.unregister();
     Ok(())
   }
 }
 
+/// Activation shared with the tasks waiting for the session bus
+///
+/// The generation is bumped by every activation and deactivation so an outdated activation is not applied.
+#[derive(Default)]
+struct ActivationState {
+  generation: usize,
+  activation: Option<MediaPlayerActivation>,
+}
+
+/// Waits for the session bus to respond to an activation, off the JS thread when run as an async task
+pub struct ActivateTask {
+  response: oneshot::Receiver<Result<(String, bool), RegistrationError>>,
+  service_name: String,
+  /// Description of the bus, used in errors
+  bus: String,
+  timeout: Duration,
+  generation: usize,
+  canceller: RegistrationCanceller,
+  activation: Arc<RwLock<ActivationState>>,
+  connection_state: Arc<RwLock<MediaPlayerConnectionState>>,
+}
+
+impl ActivateTask {
+  fn wait(&mut self) -> Result<MediaPlayerActivation, XosmsError> {
+    let (service_name, owns_name) = match self.response.recv_timeout(self.timeout) {
+      Ok(Ok(acquired)) => acquired,
+      Ok(Err(RegistrationError::NameTaken)) => {
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_NAME_TAKEN,
+            format!("{} is owned by another process", self.service_name),
+          )
+          .detail("serviceName", self.service_name.to_owned()),
+        );
+      }
+      Ok(Err(RegistrationError::BusUnavailable(reason))) => {
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_BUS_UNAVAILABLE,
+            format!("Could not connect to the {}: {}", self.bus, reason),
+          )
+          .detail("serviceName", self.service_name.to_owned())
+          .detail("bus", self.bus.to_owned())
+          .detail("reason", reason),
+        );
+      }
+      Err(oneshot::RecvTimeoutError::Disconnected) => {
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_BUS_UNAVAILABLE,
+            format!("Could not connect to the {}", self.bus),
+          )
+          .detail("serviceName", self.service_name.to_owned())
+          .detail("bus", self.bus.to_owned()),
+        );
+      }
+      Err(oneshot::RecvTimeoutError::Timeout) => {
+        self.cancel();
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_TIMEOUT,
+            format!(
+              "Timed out after {}s waiting for the service name on D-Bus",
+              self.timeout.as_secs_f64()
+            ),
+          )
+          .detail("serviceName", self.service_name.to_owned())
+          .detail("timeout", self.timeout.as_secs_f64()),
+        );
+      }
+    };
+
+    let activation = MediaPlayerActivation {
+      service_name,
+      queued: !owns_name,
+    };
+    let mut activation_state = self
+      .activation
+      .write()
+      .unwrap_or_else(|error| error.into_inner());
+    if activation_state.generation != self.generation {
+      return Err(XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_STATE,
+        "Activation was superseded by a later activation or deactivation",
+      ));
+    }
+    activation_state.activation = Some(activation.clone());
+    if let Ok(mut connection_state) = self.connection_state.write() {
+      *connection_state = MediaPlayerConnectionState::Connected;
+    }
+
+    Ok(activation)
+  }
+
+  /// Rolls back a registration which the session bus still responds to after the timeout, unless it was superseded
+  fn cancel(&self) {
+    let mut activation_state = self
+      .activation
+      .write()
+      .unwrap_or_else(|error| error.into_inner());
+    if activation_state.generation != self.generation {
+      return;
+    }
+    activation_state.generation += 1;
+    self.canceller.cancel();
+    if let Ok(mut connection_state) = self.connection_state.write() {
+      *connection_state = MediaPlayerConnectionState::Disconnected;
+    }
+  }
+}
+
+impl Task for ActivateTask {
+  type Output = Result<MediaPlayerActivation, XosmsError>;
+  type JsValue = MediaPlayerActivation;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    // The error is converted once back on the JS thread so it keeps its code and details
+    Ok(self.wait())
+  }
+
+  fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(output?)
+  }
+}
+
+/// Waits for the session bus to release the service name, off the JS thread when run as an async task
+pub struct DeactivateTask {
+  response: oneshot::Receiver<bool>,
+  timeout: Duration,
+}
+
+impl DeactivateTask {
+  fn wait(&mut self) -> Result<(), XosmsError> {
+    match self.response.recv_timeout(self.timeout) {
+      Err(oneshot::RecvTimeoutError::Timeout) => Err(
+        XosmsError::new(
+          XosmsErrorCode::XOSMS_TIMEOUT,
+          format!(
+            "Timed out after {}s waiting for the service name to be released on D-Bus",
+            self.timeout.as_secs_f64()
+          ),
+        )
+        .detail("timeout", self.timeout.as_secs_f64()),
+      ),
+      _ => Ok(()),
+    }
+  }
+}
+
+impl Task for DeactivateTask {
+  type Output = Result<(), XosmsError>;
+  type JsValue = ();
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    Ok(self.wait())
+  }
+
+  fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(output?)
+  }
+}
+
+pub enum CustomMetadataValue {
+  String(String),
+  Integer(i64),
+  Double(f64),
+  Boolean(bool),
+  Strings(Vec<String>),
+}
+
 pub struct MprisPlayerState {
   identity: String,
+  desktop_entry: String,
+  fullscreen: bool,
+  can_set_fullscreen: bool,
   can_go_next: bool,
   can_go_previous: bool,
   can_play: bool,
   can_pause: bool,
   can_seek: bool,
   can_control: bool,
+  can_raise: bool,
+  can_quit: bool,
+  supported_uri_schemes: Vec<String>,
+  supported_mime_types: Vec<String>,
   media_type: MediaPlayerMediaType,
   playback_status: MediaPlayerPlaybackStatus,
+  repeat_mode: MediaPlayerRepeatMode,
+  shuffle: bool,
   thumbnail: String,
-  artist: String,
+  artists: Vec<String>,
+  album_artists: Vec<String>,
   album_title: String,
   title: String,
   track_id: String,
+  genres: Vec<String>,
+  composers: Vec<String>,
+  lyricists: Vec<String>,
+  comments: Vec<String>,
+  track_number: Option<i32>,
+  disc_number: Option<i32>,
+  user_rating: Option<f64>,
+  use_count: Option<i32>,
+  url: String,
+  content_created: Option<f64>,
+  first_used: Option<f64>,
+  last_used: Option<f64>,
+  custom_metadata: BTreeMap<String, CustomMetadataValue>,
   position: f64,
-  last_updated_position: Instant,
+  last_updated_position: f64,
+  clock: Arc<dyn PlaybackClock>,
+  ended: bool,
   duration: f64,
+  start_time: f64,
+  min_seek_time: f64,
+  max_seek_time: f64,
+  timeline_options: Option<MediaPlayerTimelineOptions>,
   volume: f64,
   playback_rate: f64,
+  minimum_rate: f64,
+  maximum_rate: f64,
+  track_list: Vec<MediaPlayerTrack>,
+  can_edit_tracks: bool,
+  playlists: Vec<MediaPlayerPlaylist>,
+  active_playlist_id: Option<String>,
+  playlist_orderings: Vec<MediaPlayerPlaylistOrdering>,
+}
+
+fn current_position(state: &MprisPlayerState) -> f64 {
+  if state.playback_status != MediaPlayerPlaybackStatus::Playing {
+    return state.position;
+  }
+
+  let elapsed = (state.clock.now() - state.last_updated_position).max(0.0);
+  let position = state.position + elapsed * state.playback_rate;
+  if state.duration > 0.0 {
+    position.clamp(0.0, state.duration)
+  } else {
+    position.max(0.0)
+  }
+}
+
+fn seeked_message(position: f64) -> Message {
+  let seeked = OrgMprisMediaPlayer2PlayerSeeked {
+    position: seconds_to_microseconds(position),
+  };
+  seeked.to_emit_message(&Path::new("/org/mpris/MediaPlayer2").unwrap())
+}
+
+fn anchor_position(state: &mut MprisPlayerState) {
+  state.position = current_position(state);
+  state.last_updated_position = state.clock.now();
+}
+
+fn track_id_to_path(track_id: &str) -> Result<Path<'static>, XosmsError> {
+  Path::new(format!("/xosms/trackid/{}", track_id)).map_err(|_| {
+    XosmsError::new(
+      XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+      format!(
+        "{} is not a valid track id, only A-Z, a-z, 0-9 and _ may be used",
+        track_id
+      ),
+    )
+    .detail("value", track_id)
+  })
+}
+
+/// The mpris:trackid published for the track id, NoTrack when it is not set
+fn published_track_path(track_id: &str) -> Path<'static> {
+  if track_id.is_empty() {
+    return Path::new(NO_TRACK_PATH).unwrap();
+  }
+
+  track_id_to_path(track_id).unwrap_or_else(|_| Path::new(NO_TRACK_PATH).unwrap())
+}
+
+fn path_to_track_id(path: &Path) -> Option<String> {
+  path
+    .strip_prefix("/xosms/trackid/")
+    .map(|track_id| track_id.to_string())
+}
+
+fn playlist_id_to_path(playlist_id: &str) -> Result<Path<'static>, XosmsError> {
+  Path::new(format!("/xosms/playlistid/{}", playlist_id)).map_err(|_| {
+    XosmsError::new(
+      XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+      format!(
+        "{} is not a valid playlist id, only A-Z, a-z, 0-9 and _ may be used",
+        playlist_id
+      ),
+    )
+    .detail("value", playlist_id)
+  })
+}
+
+fn path_to_playlist_id(path: &Path) -> Option<String> {
+  path
+    .strip_prefix("/xosms/playlistid/")
+    .map(|playlist_id| playlist_id.to_string())
+}
+
+fn validate_minimum_playback_rate(minimum_playback_rate: f64) -> Result<(), XosmsError> {
+  if minimum_playback_rate.is_nan() || minimum_playback_rate <= 0.0 || minimum_playback_rate > 1.0 {
+    return Err(
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+        format!(
+          "{} is not a valid minimum playback rate, it must be greater than 0.0 and at most 1.0",
+          minimum_playback_rate
+        ),
+      )
+      .detail("value", minimum_playback_rate),
+    );
+  }
+
+  Ok(())
+}
+
+fn validate_maximum_playback_rate(maximum_playback_rate: f64) -> Result<(), XosmsError> {
+  if maximum_playback_rate.is_nan() || maximum_playback_rate < 1.0 {
+    return Err(
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+        format!(
+          "{} is not a valid maximum playback rate, it must be at least 1.0",
+          maximum_playback_rate
+        ),
+      )
+      .detail("value", maximum_playback_rate),
+    );
+  }
+
+  Ok(())
+}
+
+fn clamp_volume(volume: f64) -> f64 {
+  volume.clamp(0.0, 1.0)
+}
+
+fn repeat_mode_to_loop_status(repeat_mode: MediaPlayerRepeatMode) -> &'static str {
+  match repeat_mode {
+    MediaPlayerRepeatMode::Track => "Track",
+    MediaPlayerRepeatMode::Playlist => "Playlist",
+    _ => "None",
+  }
+}
+
+fn playlist_ordering_to_string(playlist_ordering: MediaPlayerPlaylistOrdering) -> &'static str {
+  match playlist_ordering {
+    MediaPlayerPlaylistOrdering::Alphabetical => "Alphabetical",
+    MediaPlayerPlaylistOrdering::CreationDate => "CreationDate",
+    MediaPlayerPlaylistOrdering::ModifiedDate => "ModifiedDate",
+    MediaPlayerPlaylistOrdering::LastPlayDate => "LastPlayDate",
+    _ => "UserDefined",
+  }
+}
+
+fn construct_playlist(playlist: &MediaPlayerPlaylist) -> Option<(Path<'static>, String, String)> {
+  Some((
+    playlist_id_to_path(&playlist.playlist_id).ok()?,
+    playlist.name.to_owned(),
+    playlist.icon.to_owned().unwrap_or_default(),
+  ))
+}
+
+fn construct_active_playlist(state: &MprisPlayerState) -> (bool, (Path<'static>, String, String)) {
+  let active_playlist = state
+    .active_playlist_id
+    .as_ref()
+    .and_then(|active_playlist_id| {
+      state
+        .playlists
+        .iter()
+        .find(|p| &p.playlist_id == active_playlist_id)
+        .and_then(construct_playlist)
+    });
+  match active_playlist {
+    Some(active_playlist) => (true, active_playlist),
+    None => (
+      false,
+      (Path::new("/").unwrap(), "".to_string(), "".to_string()),
+    ),
+  }
+}
+
+/// Validates the service name against the D-Bus naming rules for the bus name it is appended to
+fn validate_service_name(service_name: &str) -> Result<(), XosmsError> {
+  let valid_elements = service_name.split('.').all(|element| {
+    !element.is_empty()
+      && !element.starts_with(|c: char| c.is_ascii_digit())
+      && element
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+  });
+  if !valid_elements {
+    return Err(XosmsError::new(XosmsErrorCode::XOSMS_INVALID_ARGUMENT, format!(
+      "{} is not a valid service name, it must consist of dot separated elements of ASCII letters, digits, underscores and hyphens which do not start with a digit",
+      service_name
+    )).detail("value", service_name));
+  }
+
+  let bus_name_length = "org.mpris.MediaPlayer2.".len() + service_name.len();
+  if bus_name_length > 255 {
+    return Err(
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+        format!(
+          "{} is not a valid service name, it must be at most {} characters long",
+          service_name,
+          255 - "org.mpris.MediaPlayer2.".len()
+        ),
+      )
+      .detail("value", service_name),
+    );
+  }
+
+  Ok(())
+}
+
+fn bus_target(options: Option<MediaPlayerOptions>) -> Result<BusTarget, XosmsError> {
+  let options = options.unwrap_or(MediaPlayerOptions {
+    backend: None,
+    bus_type: None,
+    bus_address: None,
+  });
+  if backend::resolve_backend(options.backend)? == MediaPlayerBackend::Mock {
+    return Ok(BusTarget::Mock);
+  }
+  let bus_type = options.bus_type.unwrap_or(MediaPlayerBusType::Session);
+
+  match (bus_type, options.bus_address) {
+    (MediaPlayerBusType::Session, None) => Ok(BusTarget::Session),
+    (MediaPlayerBusType::System, None) => Ok(BusTarget::System),
+    (MediaPlayerBusType::Address, Some(address)) if !address.is_empty() => {
+      Ok(BusTarget::Address(address))
+    }
+    (MediaPlayerBusType::Private, Some(address)) if !address.is_empty() => {
+      Ok(BusTarget::Private(address))
+    }
+    (MediaPlayerBusType::Session | MediaPlayerBusType::System, Some(address)) => Err(
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+        format!("{:?} bus type does not take a bus address", bus_type),
+      )
+      .detail("busType", bus_type as i32)
+      .detail("busAddress", address),
+    ),
+    (_, _) => Err(
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+        format!("{:?} bus type requires a bus address", bus_type),
+      )
+      .detail("busType", bus_type as i32),
+    ),
+  }
+}
+
+fn validate_simulated_seconds(seconds: f64) -> Result<(), XosmsError> {
+  if !seconds.is_finite() {
+    return Err(
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+        "Simulated positions and offsets must be a finite number of seconds",
+      )
+      .detail("value", seconds),
+    );
+  }
+
+  Ok(())
+}
+
+fn seconds_to_microseconds(seconds: f64) -> i64 {
+  FloatDuration::seconds(seconds)
+    .as_microseconds()
+    .max(i64::MIN as f64)
+    .min(i64::MAX as f64)
+    .round() as i64
+}
+
+fn validate_timeout(timeout: Option<f64>) -> Result<Duration, XosmsError> {
+  let timeout = timeout.unwrap_or(DEFAULT_BUS_TIMEOUT);
+  Duration::try_from_secs_f64(timeout)
+    .ok()
+    .filter(|duration| !duration.is_zero())
+    .ok_or_else(|| {
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+        "Timeout must be a positive number of seconds",
+      )
+      .detail("value", timeout)
+    })
+}
+
+fn timestamp_to_iso8601(timestamp: f64) -> String {
+  // Converts days since the unix epoch to a civil date, see http://howardhinnant.github.io/date_algorithms.html
+  let seconds = (timestamp / 1000.0).floor() as i64;
+  let days = seconds.div_euclid(86400);
+  let seconds_of_day = seconds.rem_euclid(86400);
+
+  let z = days + 719468;
+  let era = z.div_euclid(146097);
+  let day_of_era = z - era * 146097;
+  let year_of_era =
+    (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+  let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+  let mp = (5 * day_of_year + 2) / 153;
+  let day = day_of_year - (153 * mp + 2) / 5 + 1;
+  let month = if mp < 10 { mp + 3 } else { mp - 9 };
+  let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+  format!(
+    "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+    year,
+    month,
+    day,
+    seconds_of_day / 3600,
+    seconds_of_day % 3600 / 60,
+    seconds_of_day % 60
+  )
+}
+
+struct TimelineBounds {
+  start_time: f64,
+  min_seek_time: f64,
+  max_seek_time: f64,
+}
+
+fn validate_timeline(
+  duration: f64,
+  position: f64,
+  options: &Option<MediaPlayerTimelineOptions>,
+) -> Result<TimelineBounds, XosmsError> {
+  if duration < 0.0 {
+    return Err(
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_TIMELINE,
+        "Duration cannot be less than 0",
+      )
+      .detail("duration", duration),
+    );
+  }
+  if position < 0.0 {
+    return Err(
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_TIMELINE,
+        "Position cannot be less than 0",
+      )
+      .detail("position", position),
+    );
+  }
+  if position > duration {
+    return Err(
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_TIMELINE,
+        "Position cannot be greather than provided duration",
+      )
+      .detail("position", position)
+      .detail("duration", duration),
+    );
+  }
+
+  let start_time = options
+    .as_ref()
+    .and_then(|options| options.start_time)
+    .unwrap_or(0.0);
+  let min_seek_time = options
+    .as_ref()
+    .and_then(|options| options.min_seek_time)
+    .unwrap_or(start_time);
+  let max_seek_time = options
+    .as_ref()
+    .and_then(|options| options.max_seek_time)
+    .unwrap_or(duration);
+  if start_time.is_nan() || start_time < 0.0 || start_time > position {
+    return Err(
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_TIMELINE,
+        "Start time must be between 0 and the provided position",
+      )
+      .detail("startTime", start_time)
+      .detail("position", position),
+    );
+  }
+  if min_seek_time.is_nan() || min_seek_time < start_time {
+    return Err(
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_TIMELINE,
+        "Min seek time cannot be less than the start time",
+      )
+      .detail("minSeekTime", min_seek_time)
+      .detail("startTime", start_time),
+    );
+  }
+  if max_seek_time.is_nan() || max_seek_time < min_seek_time || max_seek_time > duration {
+    return Err(
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_TIMELINE,
+        "Max seek time must be between the min seek time and the provided duration",
+      )
+      .detail("maxSeekTime", max_seek_time)
+      .detail("minSeekTime", min_seek_time)
+      .detail("duration", duration),
+    );
+  }
+  if let Some(seek_threshold) = options.as_ref().and_then(|options| options.seek_threshold) {
+    if seek_threshold.is_nan() || seek_threshold < 0.0 {
+      return Err(
+        XosmsError::new(
+          XosmsErrorCode::XOSMS_INVALID_TIMELINE,
+          "Seek threshold cannot be less than 0",
+        )
+        .detail("seekThreshold", seek_threshold),
+      );
+    }
+  }
+
+  Ok(TimelineBounds {
+    start_time,
+    min_seek_time,
+    max_seek_time,
+  })
+}
+
+fn validate_custom_metadata_key(key: &str) -> Result<(), XosmsError> {
+  let is_valid_part = |part: &str| {
+    !part.is_empty()
+      && part
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '.')
+  };
+
+  match key.split_once(':') {
+    Some(("mpris", _)) => Err(
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+        format!(
+          "{} is not a valid custom metadata key, the mpris namespace is reserved",
+          key
+        ),
+      )
+      .detail("value", key),
+    ),
+    Some(_) if STRUCTURED_METADATA_KEYS.contains(&key) => Err(
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+        format!(
+          "{} is not a valid custom metadata key, it is set through its MediaPlayer property",
+          key
+        ),
+      )
+      .detail("value", key),
+    ),
+    Some((namespace, name)) if is_valid_part(namespace) && is_valid_part(name) => Ok(()),
+    _ => Err(
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+        format!(
+          "{} is not a valid custom metadata key, it must be in the form of namespace:name",
+          key
+        ),
+      )
+      .detail("value", key),
+    ),
+  }
+}
+
+fn js_to_custom_metadata_value(value: JsUnknown) -> napi::Result<CustomMetadataValue> {
+  match value.get_type()? {
+    ValueType::String => Ok(CustomMetadataValue::String(
+      JsString::try_from(value)?.into_utf8()?.into_owned()?,
+    )),
+    ValueType::Number => {
+      let number = JsNumber::try_from(value)?.get_double()?;
+      if number.fract() == 0.0 && number >= i64::MIN as f64 && number <= i64::MAX as f64 {
+        Ok(CustomMetadataValue::Integer(number as i64))
+      } else if number.is_finite() {
+        Ok(CustomMetadataValue::Double(number))
+      } else {
+        Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+            format!("{} is not a valid custom metadata value", number),
+          )
+          .detail("value", number)
+          .into(),
+        )
+      }
+    }
+    ValueType::Boolean => Ok(CustomMetadataValue::Boolean(
+      JsBoolean::try_from(value)?.get_value()?,
+    )),
+    ValueType::Object if value.is_date()? => {
+      let timestamp = JsDate::try_from(value)?.value_of()?;
+      if !timestamp.is_finite() {
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+            "An invalid date is not a valid custom metadata value",
+          )
+          .into(),
+        );
+      }
+
+      Ok(CustomMetadataValue::String(timestamp_to_iso8601(timestamp)))
+    }
+    ValueType::Object if value.is_array()? => {
+      let array = JsObject::try_from(value)?;
+      let mut strings = vec![];
+      for index in 0..array.get_array_length()? {
+        let element: JsUnknown = array.get_element(index)?;
+        if element.get_type()? != ValueType::String {
+          return Err(
+            XosmsError::new(
+              XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+              "Only arrays of strings are valid custom metadata values",
+            )
+            .into(),
+          );
+        }
+        strings.push(JsString::try_from(element)?.into_utf8()?.into_owned()?);
+      }
+
+      Ok(CustomMetadataValue::Strings(strings))
+    }
+    value_type => Err(
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+        format!("{} is not a valid custom metadata value type", value_type),
+      )
+      .detail("type", value_type.to_string())
+      .into(),
+    ),
+  }
+}
+
+fn insert_string(metadata: &mut PropMap, key: &str, value: &str) {
+  if !value.is_empty() {
+    metadata.insert(key.to_string(), Variant(Box::new(value.to_owned())));
+  }
+}
+
+fn insert_strings(metadata: &mut PropMap, key: &str, values: &[String]) {
+  if !values.is_empty() {
+    metadata.insert(key.to_string(), Variant(Box::new(values.to_owned())));
+  }
+}
+
+fn insert_date(metadata: &mut PropMap, key: &str, value: Option<f64>) {
+  if let Some(value) = value {
+    metadata.insert(
+      key.to_string(),
+      Variant(Box::new(timestamp_to_iso8601(value))),
+    );
+  }
+}
+
+fn construct_metadata(state: &MprisPlayerState) -> PropMap {
+  let mut metadata = PropMap::new();
+  metadata.insert(
+    "mpris:trackid".to_string(),
+    Variant(Box::new(published_track_path(&state.track_id))),
+  );
+  if state.duration > 0.0 {
+    metadata.insert(
+      "mpris:length".to_string(),
+      Variant(Box::new(
+        FloatDuration::seconds(state.duration)
+          .as_microseconds()
+          .max(i64::MIN as f64)
+          .min(i64::MAX as f64)
+          .round() as i64,
+      )),
+    );
+  }
+  insert_string(&mut metadata, "mpris:artUrl", &state.thumbnail);
+  insert_string(&mut metadata, "xesam:title", &state.title);
+  insert_string(&mut metadata, "xesam:album", &state.album_title);
+  insert_strings(&mut metadata, "xesam:artist", &state.artists);
+  insert_strings(&mut metadata, "xesam:albumArtist", &state.album_artists);
+  insert_strings(&mut metadata, "xesam:genre", &state.genres);
+  insert_strings(&mut metadata, "xesam:composer", &state.composers);
+  insert_strings(&mut metadata, "xesam:lyricist", &state.lyricists);
+  insert_strings(&mut metadata, "xesam:comment", &state.comments);
+  if let Some(track_number) = state.track_number {
+    metadata.insert(
+      "xesam:trackNumber".to_string(),
+      Variant(Box::new(track_number)),
+    );
+  }
+  if let Some(disc_number) = state.disc_number {
+    metadata.insert(
+      "xesam:discNumber".to_string(),
+      Variant(Box::new(disc_number)),
+    );
+  }
+  if let Some(user_rating) = state.user_rating {
+    metadata.insert(
+      "xesam:userRating".to_string(),
+      Variant(Box::new(user_rating)),
+    );
+  }
+  if let Some(use_count) = state.use_count {
+    metadata.insert("xesam:useCount".to_string(), Variant(Box::new(use_count)));
+  }
+  insert_string(&mut metadata, "xesam:url", &state.url);
+  insert_date(&mut metadata, "xesam:contentCreated", state.content_created);
+  insert_date(&mut metadata, "xesam:firstUsed", state.first_used);
+  insert_date(&mut metadata, "xesam:lastUsed", state.last_used);
+  for (key, value) in &state.custom_metadata {
+    let value: Variant<Box<dyn RefArg>> = match value {
+      CustomMetadataValue::String(value) => Variant(Box::new(value.to_owned())),
+      CustomMetadataValue::Integer(value) => Variant(Box::new(*value)),
+      CustomMetadataValue::Double(value) => Variant(Box::new(*value)),
+      CustomMetadataValue::Boolean(value) => Variant(Box::new(*value)),
+      CustomMetadataValue::Strings(value) => Variant(Box::new(value.to_owned())),
+    };
+    metadata.insert(key.to_owned(), value);
+  }
+
+  metadata
+}
+
+fn construct_track_metadata(track_path: &Path<'static>, track: &MediaPlayerTrack) -> PropMap {
+  let mut metadata = PropMap::new();
+  metadata.insert(
+    "mpris:trackid".to_string(),
+    Variant(Box::new(track_path.to_owned())),
+  );
+  if let Some(duration) = track.duration {
+    metadata.insert(
+      "mpris:length".to_string(),
+      Variant(Box::new(
+        FloatDuration::seconds(duration)
+          .as_microseconds()
+          .max(i64::MIN as f64)
+          .min(i64::MAX as f64)
+          .round() as i64,
+      )),
+    );
+  }
+  if let Some(thumbnail) = &track.thumbnail {
+    metadata.insert(
+      "mpris:artUrl".to_string(),
+      Variant(Box::new(thumbnail.to_owned())),
+    );
+  }
+  if let Some(title) = &track.title {
+    metadata.insert(
+      "xesam:title".to_string(),
+      Variant(Box::new(title.to_owned())),
+    );
+  }
+  if let Some(album_title) = &track.album_title {
+    metadata.insert(
+      "xesam:album".to_string(),
+      Variant(Box::new(album_title.to_owned())),
+    );
+  }
+  if let Some(artist) = &track.artist {
+    metadata.insert(
+      "xesam:artist".to_string(),
+      Variant(Box::new(vec![artist.to_owned()])),
+    );
+  }
+  metadata
 }
 
 struct MprisPlayer {
@@ -870,40 +4601,114 @@ struct MprisPlayer {
     Arc<DashMap<usize, ThreadsafeFunction<f64, ErrorStrategy::CalleeHandled>>>,
   playback_position_seeked_listeners:
     Arc<DashMap<usize, ThreadsafeFunction<f64, ErrorStrategy::CalleeHandled>>>,
+  track_go_to_listeners:
+    Arc<DashMap<usize, ThreadsafeFunction<String, ErrorStrategy::CalleeHandled>>>,
+  track_add_listeners:
+    Arc<DashMap<usize, ThreadsafeFunction<TrackAddArgs, ErrorStrategy::CalleeHandled>>>,
+  track_remove_listeners:
+    Arc<DashMap<usize, ThreadsafeFunction<String, ErrorStrategy::CalleeHandled>>>,
+  playlist_activated_listeners:
+    Arc<DashMap<usize, ThreadsafeFunction<String, ErrorStrategy::CalleeHandled>>>,
+  repeat_mode_changed_listeners:
+    Arc<DashMap<usize, ThreadsafeFunction<MediaPlayerRepeatMode, ErrorStrategy::CalleeHandled>>>,
+  shuffle_changed_listeners:
+    Arc<DashMap<usize, ThreadsafeFunction<bool, ErrorStrategy::CalleeHandled>>>,
+  volume_changed_listeners:
+    Arc<DashMap<usize, ThreadsafeFunction<f64, ErrorStrategy::CalleeHandled>>>,
+  rate_changed_listeners:
+    Arc<DashMap<usize, ThreadsafeFunction<f64, ErrorStrategy::CalleeHandled>>>,
+  raise_listeners: Arc<DashMap<usize, ThreadsafeFunction<(), ErrorStrategy::CalleeHandled>>>,
+  quit_listeners: Arc<DashMap<usize, ThreadsafeFunction<(), ErrorStrategy::CalleeHandled>>>,
+  open_uri_listeners: Arc<DashMap<usize, ThreadsafeFunction<String, ErrorStrategy::CalleeHandled>>>,
+  fullscreen_changed_listeners:
+    Arc<DashMap<usize, ThreadsafeFunction<bool, ErrorStrategy::CalleeHandled>>>,
   state: Arc<RwLock<MprisPlayerState>>,
 }
 
 impl OrgMprisMediaPlayer2 for MprisPlayer {
   fn raise(&mut self) -> Result<(), ::dbus::MethodErr> {
+    if let Ok(state) = self.state.read() {
+      if !state.can_raise {
+        return Ok(());
+      }
+    }
+
+    for listener in self.raise_listeners.iter() {
+      listener.call(Ok(()), ThreadsafeFunctionCallMode::NonBlocking);
+    }
+
     Ok(())
   }
 
   fn quit(&mut self) -> Result<(), ::dbus::MethodErr> {
+    if let Ok(state) = self.state.read() {
+      if !state.can_quit {
+        return Ok(());
+      }
+    }
+
+    for listener in self.quit_listeners.iter() {
+      listener.call(Ok(()), ThreadsafeFunctionCallMode::NonBlocking);
+    }
+
     Ok(())
   }
 
-  fn can_quit(&self) -> Result<bool, ::dbus::MethodErr> {
-    Ok(false)
-  }
+  fn can_quit(&self) -> Result<bool, ::dbus::MethodErr> {
+    if let Ok(state) = self.state.read() {
+      return Ok(state.can_quit);
+    }
+
+    Err(MethodErr::failed("An error occurred while reading CanQuit"))
+  }
+
+  fn fullscreen(&self) -> Result<bool, ::dbus::MethodErr> {
+    if let Ok(state) = self.state.read() {
+      return Ok(state.fullscreen);
+    }
+
+    Err(MethodErr::failed(
+      "An error occurred while reading Fullscreen",
+    ))
+  }
+
+  fn set_fullscreen(&self, value: bool) -> Result<(), ::dbus::MethodErr> {
+    if let Ok(state) = self.state.read() {
+      if !state.can_set_fullscreen {
+        return Err(MethodErr::failed("Fullscreen cannot be set"));
+      }
+    }
 
-  fn fullscreen(&self) -> Result<bool, ::dbus::MethodErr> {
-    Ok(false)
-  }
+    // The media player decides whether to accept the fullscreen state by setting it
+    for listener in self.fullscreen_changed_listeners.iter() {
+      listener.call(Ok(value), ThreadsafeFunctionCallMode::NonBlocking);
+    }
 
-  fn set_fullscreen(&self, _value: bool) -> Result<(), ::dbus::MethodErr> {
-    Err(MethodErr::failed("Fullscreen cannot be set"))
+    Ok(())
   }
 
   fn can_set_fullscreen(&self) -> Result<bool, ::dbus::MethodErr> {
-    Ok(false)
+    if let Ok(state) = self.state.read() {
+      return Ok(state.can_set_fullscreen);
+    }
+
+    Err(MethodErr::failed(
+      "An error occurred while reading CanSetFullscreen",
+    ))
   }
 
   fn can_raise(&self) -> Result<bool, ::dbus::MethodErr> {
-    Ok(false)
+    if let Ok(state) = self.state.read() {
+      return Ok(state.can_raise);
+    }
+
+    Err(MethodErr::failed(
+      "An error occurred while reading CanRaise",
+    ))
   }
 
   fn has_track_list(&self) -> Result<bool, ::dbus::MethodErr> {
-    Ok(false)
+    Ok(true)
   }
 
   fn identity(&self) -> Result<String, ::dbus::MethodErr> {
@@ -911,19 +4716,39 @@ impl OrgMprisMediaPlayer2 for MprisPlayer {
       return Ok(state.identity.to_owned());
     }
 
-    Err(MethodErr::failed("An error occurred while reading Volume"))
+    Err(MethodErr::failed(
+      "An error occurred while reading Identity",
+    ))
   }
 
   fn desktop_entry(&self) -> Result<String, ::dbus::MethodErr> {
-    Err(MethodErr::no_property("DesktopEntry is not implemented"))
+    if let Ok(state) = self.state.read() {
+      return Ok(state.desktop_entry.to_owned());
+    }
+
+    Err(MethodErr::failed(
+      "An error occurred while reading DesktopEntry",
+    ))
   }
 
   fn supported_uri_schemes(&self) -> Result<Vec<String>, ::dbus::MethodErr> {
-    Ok(vec![])
+    if let Ok(state) = self.state.read() {
+      return Ok(state.supported_uri_schemes.clone());
+    }
+
+    Err(MethodErr::failed(
+      "An error occurred while reading SupportedUriSchemes",
+    ))
   }
 
   fn supported_mime_types(&self) -> Result<Vec<String>, ::dbus::MethodErr> {
-    Ok(vec![])
+    if let Ok(state) = self.state.read() {
+      return Ok(state.supported_mime_types.clone());
+    }
+
+    Err(MethodErr::failed(
+      "An error occurred while reading SupportedMimeTypes",
+    ))
   }
 }
 
@@ -1059,11 +4884,13 @@ impl OrgMprisMediaPlayer2Player for MprisPlayer {
       if position < 0 {
         return Ok(());
       }
-      if Duration::from_micros(position as u64).as_secs_f64() > state.position {
+      // Positions outside of the seekable range of the timeline are ignored
+      let seconds = Duration::from_micros(position as u64).as_secs_f64();
+      if seconds < state.min_seek_time || (state.duration > 0.0 && seconds > state.max_seek_time) {
         return Ok(());
       }
       // The track id being different signifies that this may have been called too late and should be ignored
-      if track_id.to_string() != format!("/xosms/trackid/{}", state.track_id) {
+      if track_id != published_track_path(&state.track_id) {
         return Ok(());
       }
     }
@@ -1078,8 +4905,22 @@ impl OrgMprisMediaPlayer2Player for MprisPlayer {
     Ok(())
   }
 
-  fn open_uri(&mut self, _uri: String) -> Result<(), ::dbus::MethodErr> {
-    Err(MethodErr::failed("OpenUri is not supported"))
+  fn open_uri(&mut self, uri: String) -> Result<(), ::dbus::MethodErr> {
+    if let Ok(state) = self.state.read() {
+      let scheme = uri.split_once(':').map(|(scheme, _)| scheme.to_lowercase());
+      if !scheme.is_some_and(|scheme| state.supported_uri_schemes.contains(&scheme)) {
+        return Err(MethodErr::from((
+          "org.freedesktop.DBus.Error.NotSupported",
+          format!("The scheme of {} is not supported", uri),
+        )));
+      }
+    }
+
+    for listener in self.open_uri_listeners.iter() {
+      listener.call(Ok(uri.clone()), ThreadsafeFunctionCallMode::NonBlocking);
+    }
+
+    Ok(())
   }
 
   fn playback_status(&self) -> Result<String, ::dbus::MethodErr> {
@@ -1098,66 +4939,106 @@ impl OrgMprisMediaPlayer2Player for MprisPlayer {
   }
 
   fn loop_status(&self) -> Result<String, ::dbus::MethodErr> {
-    Err(MethodErr::no_property("LoopStatus is not implemented"))
+    if let Ok(state) = self.state.read() {
+      return Ok(repeat_mode_to_loop_status(state.repeat_mode).to_string());
+    }
+
+    Err(MethodErr::failed(
+      "An error occurred while reading LoopStatus",
+    ))
   }
 
-  fn set_loop_status(&self, _value: String) -> Result<(), ::dbus::MethodErr> {
-    Err(MethodErr::no_property("LoopStatus is not implemented"))
+  fn set_loop_status(&self, value: String) -> Result<(), ::dbus::MethodErr> {
+    if let Ok(state) = self.state.read() {
+      if !state.can_control {
+        return Err(MethodErr::failed("This media player cannot be controlled"));
+      }
+    }
+
+    let repeat_mode = match value.as_str() {
+      "None" => MediaPlayerRepeatMode::None,
+      "Track" => MediaPlayerRepeatMode::Track,
+      "Playlist" => MediaPlayerRepeatMode::Playlist,
+      _ => return Err(MethodErr::invalid_arg(&value)),
+    };
+
+    // The media player decides whether to accept the repeat mode by setting it
+    for listener in self.repeat_mode_changed_listeners.iter() {
+      listener.call(Ok(repeat_mode), ThreadsafeFunctionCallMode::NonBlocking);
+    }
+
+    Ok(())
   }
 
   fn rate(&self) -> Result<f64, ::dbus::MethodErr> {
-    Ok(1.0)
+    if let Ok(state) = self.state.read() {
+      return Ok(state.playback_rate);
+    }
+
+    Err(MethodErr::failed("An error occurred while reading Rate"))
   }
 
-  fn set_rate(&self, _value: f64) -> Result<(), ::dbus::MethodErr> {
-    // Clients should not be calling this with 0.0 but D-Bus states rate being set to 0.0 is equivalent to pausing
-    // TODO: Make 0.0 call pause
+  fn set_rate(&self, value: f64) -> Result<(), ::dbus::MethodErr> {
+    if let Ok(state) = self.state.read() {
+      if !state.can_control {
+        return Err(MethodErr::failed("This media player cannot be controlled"));
+      }
+
+      // Clients should not be calling this with 0.0 but D-Bus states rate being set to 0.0 is equivalent to pausing
+      if value == 0.0 {
+        if state.can_pause {
+          for listener in self.button_pressed_listeners.iter() {
+            listener.call(
+              Ok("pause".to_string()),
+              ThreadsafeFunctionCallMode::NonBlocking,
+            );
+          }
+        }
 
-    Ok(())
+        return Ok(());
+      }
+
+      if value.is_nan() || value < state.minimum_rate || value > state.maximum_rate {
+        return Err(MethodErr::invalid_arg(&value));
+      }
+
+      // The media player decides whether to accept the rate by setting it
+      for listener in self.rate_changed_listeners.iter() {
+        listener.call(Ok(value), ThreadsafeFunctionCallMode::NonBlocking);
+      }
+
+      return Ok(());
+    }
+
+    Err(MethodErr::failed("An error occurred while writing Rate"))
   }
 
   fn shuffle(&self) -> Result<bool, ::dbus::MethodErr> {
-    Err(MethodErr::no_property("Shuffle is not implemented"))
+    if let Ok(state) = self.state.read() {
+      return Ok(state.shuffle);
+    }
+
+    Err(MethodErr::failed("An error occurred while reading Shuffle"))
   }
 
-  fn set_shuffle(&self, _value: bool) -> Result<(), ::dbus::MethodErr> {
-    Err(MethodErr::no_property("Shuffle is not implemented"))
+  fn set_shuffle(&self, value: bool) -> Result<(), ::dbus::MethodErr> {
+    if let Ok(state) = self.state.read() {
+      if !state.can_control {
+        return Err(MethodErr::failed("This media player cannot be controlled"));
+      }
+    }
+
+    // The media player decides whether to accept the shuffle state by setting it
+    for listener in self.shuffle_changed_listeners.iter() {
+      listener.call(Ok(value), ThreadsafeFunctionCallMode::NonBlocking);
+    }
+
+    Ok(())
   }
 
   fn metadata(&self) -> Result<::dbus::arg::PropMap, ::dbus::MethodErr> {
     if let Ok(state) = self.state.read() {
-      let mut metadata = PropMap::new();
-      metadata.insert(
-        "mpris:trackid".to_string(),
-        Variant(Box::new(format!("/xosms/trackid/{}", state.track_id))),
-      );
-      metadata.insert(
-        "mpris:length".to_string(),
-        Variant(Box::new(
-          FloatDuration::seconds(state.duration)
-            .as_microseconds()
-            .max(i64::MIN as f64)
-            .min(i64::MAX as f64)
-            .round() as i64,
-        )),
-      );
-      metadata.insert(
-        "mpris:artUrl".to_string(),
-        Variant(Box::new(state.thumbnail.to_owned())),
-      );
-      metadata.insert(
-        "xesam:title".to_string(),
-        Variant(Box::new(state.title.to_owned())),
-      );
-      metadata.insert(
-        "xesam:album".to_string(),
-        Variant(Box::new(state.album_title.to_owned())),
-      );
-      metadata.insert(
-        "xesam:artist".to_string(),
-        Variant(Box::new(state.artist.to_owned())),
-      );
-      return Ok(metadata);
+      return Ok(construct_metadata(&state));
     }
 
     Err(MethodErr::failed(
@@ -1174,18 +5055,29 @@ impl OrgMprisMediaPlayer2Player for MprisPlayer {
   }
 
   fn set_volume(&self, value: f64) -> Result<(), ::dbus::MethodErr> {
-    if let Ok(mut state) = self.state.write() {
-      state.volume = value;
-      return Ok(());
+    if let Ok(state) = self.state.read() {
+      if !state.can_control {
+        return Err(MethodErr::failed("This media player cannot be controlled"));
+      }
+    }
+
+    if value.is_nan() {
+      return Err(MethodErr::invalid_arg(&value));
+    }
+
+    // The media player decides whether to accept the volume by setting it
+    let volume = clamp_volume(value);
+    for listener in self.volume_changed_listeners.iter() {
+      listener.call(Ok(volume), ThreadsafeFunctionCallMode::NonBlocking);
     }
 
-    Err(MethodErr::failed("An error occurred while writing Volume"))
+    Ok(())
   }
 
   fn position(&self) -> Result<i64, ::dbus::MethodErr> {
     if let Ok(state) = self.state.read() {
       return Ok(
-        FloatDuration::seconds(state.position)
+        FloatDuration::seconds(current_position(&state))
           .as_microseconds()
           .max(i64::MIN as f64)
           .min(i64::MAX as f64)
@@ -1197,11 +5089,23 @@ impl OrgMprisMediaPlayer2Player for MprisPlayer {
   }
 
   fn minimum_rate(&self) -> Result<f64, ::dbus::MethodErr> {
-    Ok(1.0)
+    if let Ok(state) = self.state.read() {
+      return Ok(state.minimum_rate);
+    }
+
+    Err(MethodErr::failed(
+      "An error occurred while reading MinimumRate",
+    ))
   }
 
   fn maximum_rate(&self) -> Result<f64, ::dbus::MethodErr> {
-    Ok(1.0)
+    if let Ok(state) = self.state.read() {
+      return Ok(state.maximum_rate);
+    }
+
+    Err(MethodErr::failed(
+      "An error occurred while reading MaximumRate",
+    ))
   }
 
   fn can_go_next(&self) -> Result<bool, ::dbus::MethodErr> {
@@ -1260,3 +5164,247 @@ impl OrgMprisMediaPlayer2Player for MprisPlayer {
     ))
   }
 }
+
+impl OrgMprisMediaPlayer2TrackList for MprisPlayer {
+  fn get_tracks_metadata(
+    &mut self,
+    track_ids: Vec<::dbus::Path<'static>>,
+  ) -> Result<Vec<PropMap>, ::dbus::MethodErr> {
+    if let Ok(state) = self.state.read() {
+      let mut tracks_metadata = vec![];
+      for track_path in track_ids.iter() {
+        if let Some(track_id) = path_to_track_id(track_path) {
+          if let Some(track) = state.track_list.iter().find(|t| t.track_id == track_id) {
+            tracks_metadata.push(construct_track_metadata(track_path, track));
+          }
+        }
+      }
+      return Ok(tracks_metadata);
+    }
+
+    Err(MethodErr::failed(
+      "An error occurred while reading TrackList",
+    ))
+  }
+
+  fn add_track(
+    &mut self,
+    uri: String,
+    after_track: ::dbus::Path<'static>,
+    set_as_current: bool,
+  ) -> Result<(), ::dbus::MethodErr> {
+    if let Ok(state) = self.state.read() {
+      if !state.can_edit_tracks {
+        return Ok(());
+      }
+    }
+
+    let after_track_id = path_to_track_id(&after_track);
+    for listener in self.track_add_listeners.iter() {
+      listener.call(
+        Ok((uri.to_owned(), after_track_id.to_owned(), set_as_current)),
+        ThreadsafeFunctionCallMode::NonBlocking,
+      );
+    }
+
+    Ok(())
+  }
+
+  fn remove_track(&mut self, track_id: ::dbus::Path<'static>) -> Result<(), ::dbus::MethodErr> {
+    let track_id = match path_to_track_id(&track_id) {
+      Some(track_id) => track_id,
+      None => return Ok(()),
+    };
+    if let Ok(state) = self.state.read() {
+      if !state.can_edit_tracks {
+        return Ok(());
+      }
+      if !state.track_list.iter().any(|t| t.track_id == track_id) {
+        return Ok(());
+      }
+    }
+
+    for listener in self.track_remove_listeners.iter() {
+      listener.call(
+        Ok(track_id.to_owned()),
+        ThreadsafeFunctionCallMode::NonBlocking,
+      );
+    }
+
+    Ok(())
+  }
+
+  fn go_to(&mut self, track_id: ::dbus::Path<'static>) -> Result<(), ::dbus::MethodErr> {
+    let track_id = match path_to_track_id(&track_id) {
+      Some(track_id) => track_id,
+      None => return Ok(()),
+    };
+    if let Ok(state) = self.state.read() {
+      // Track ids that are not in the track list are ignored as the track list may have changed since
+      if !state.track_list.iter().any(|t| t.track_id == track_id) {
+        return Ok(());
+      }
+    }
+
+    for listener in self.track_go_to_listeners.iter() {
+      listener.call(
+        Ok(track_id.to_owned()),
+        ThreadsafeFunctionCallMode::NonBlocking,
+      );
+    }
+
+    Ok(())
+  }
+
+  fn tracks(&self) -> Result<Vec<::dbus::Path<'static>>, ::dbus::MethodErr> {
+    if let Ok(state) = self.state.read() {
+      return Ok(
+        state
+          .track_list
+          .iter()
+          .filter_map(|t| track_id_to_path(&t.track_id).ok())
+          .collect(),
+      );
+    }
+
+    Err(MethodErr::failed("An error occurred while reading Tracks"))
+  }
+
+  fn can_edit_tracks(&self) -> Result<bool, ::dbus::MethodErr> {
+    if let Ok(state) = self.state.read() {
+      return Ok(state.can_edit_tracks);
+    }
+
+    Err(MethodErr::failed(
+      "An error occurred while reading CanEditTracks",
+    ))
+  }
+}
+
+impl OrgMprisMediaPlayer2Playlists for MprisPlayer {
+  fn activate_playlist(
+    &mut self,
+    playlist_id: ::dbus::Path<'static>,
+  ) -> Result<(), ::dbus::MethodErr> {
+    let playlist_id = match path_to_playlist_id(&playlist_id) {
+      Some(playlist_id) => playlist_id,
+      None => return Err(MethodErr::invalid_arg("PlaylistId is not a known playlist")),
+    };
+    if let Ok(state) = self.state.read() {
+      if !state.playlists.iter().any(|p| p.playlist_id == playlist_id) {
+        return Err(MethodErr::invalid_arg("PlaylistId is not a known playlist"));
+      }
+    }
+
+    for listener in self.playlist_activated_listeners.iter() {
+      listener.call(
+        Ok(playlist_id.to_owned()),
+        ThreadsafeFunctionCallMode::NonBlocking,
+      );
+    }
+
+    Ok(())
+  }
+
+  fn get_playlists(
+    &mut self,
+    index: u32,
+    max_count: u32,
+    order: String,
+    reverse_order: bool,
+  ) -> Result<Vec<(::dbus::Path<'static>, String, String)>, ::dbus::MethodErr> {
+    if let Ok(state) = self.state.read() {
+      let playlist_ordering = state
+        .playlist_orderings
+        .iter()
+        .find(|o| playlist_ordering_to_string(**o) == order);
+      let playlist_ordering = match playlist_ordering {
+        Some(playlist_ordering) => *playlist_ordering,
+        None => return Err(MethodErr::invalid_arg("Order is not a supported ordering")),
+      };
+
+      let mut playlists: Vec<&MediaPlayerPlaylist> = state.playlists.iter().collect();
+      match playlist_ordering {
+        MediaPlayerPlaylistOrdering::Alphabetical => {
+          playlists.sort_by_key(|p| p.name.to_lowercase());
+        }
+        MediaPlayerPlaylistOrdering::CreationDate => {
+          playlists.sort_by(|a, b| {
+            a.creation_date
+              .partial_cmp(&b.creation_date)
+              .unwrap_or(Ordering::Equal)
+          });
+        }
+        MediaPlayerPlaylistOrdering::ModifiedDate => {
+          playlists.sort_by(|a, b| {
+            a.modified_date
+              .partial_cmp(&b.modified_date)
+              .unwrap_or(Ordering::Equal)
+          });
+        }
+        MediaPlayerPlaylistOrdering::LastPlayDate => {
+          playlists.sort_by(|a, b| {
+            a.last_play_date
+              .partial_cmp(&b.last_play_date)
+              .unwrap_or(Ordering::Equal)
+          });
+        }
+        _ => {}
+      }
+      if reverse_order {
+        playlists.reverse();
+      }
+
+      return Ok(
+        playlists
+          .into_iter()
+          .skip(index as usize)
+          .take(max_count as usize)
+          .filter_map(construct_playlist)
+          .collect(),
+      );
+    }
+
+    Err(MethodErr::failed(
+      "An error occurred while reading Playlists",
+    ))
+  }
+
+  fn playlist_count(&self) -> Result<u32, ::dbus::MethodErr> {
+    if let Ok(state) = self.state.read() {
+      return Ok(state.playlists.len() as u32);
+    }
+
+    Err(MethodErr::failed(
+      "An error occurred while reading PlaylistCount",
+    ))
+  }
+
+  fn orderings(&self) -> Result<Vec<String>, ::dbus::MethodErr> {
+    if let Ok(state) = self.state.read() {
+      return Ok(
+        state
+          .playlist_orderings
+          .iter()
+          .map(|o| playlist_ordering_to_string(*o).to_string())
+          .collect(),
+      );
+    }
+
+    Err(MethodErr::failed(
+      "An error occurred while reading Orderings",
+    ))
+  }
+
+  fn active_playlist(
+    &self,
+  ) -> Result<(bool, (::dbus::Path<'static>, String, String)), ::dbus::MethodErr> {
+    if let Ok(state) = self.state.read() {
+      return Ok(construct_active_playlist(&state));
+    }
+
+    Err(MethodErr::failed(
+      "An error occurred while reading ActivePlaylist",
+    ))
+  }
+}
@@ -1,23 +1,34 @@
-use std::{sync::Arc, time::Duration};
+use std::{
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+  },
+  time::Duration,
+};
 
-use dashmap::DashMap;
+use dashmap::{mapref::entry::Entry, DashMap};
 use napi::{
-  bindgen_prelude::ObjectFinalize,
+  bindgen_prelude::{AsyncTask, ClassInstance, ObjectFinalize},
   threadsafe_function::{ErrorStrategy, ThreadsafeFunction, ThreadsafeFunctionCallMode},
-  Env, JsFunction, NapiRaw,
+  Env, JsFunction, JsUnknown, NapiRaw, Task,
 };
 use windows::{
   core::HSTRING,
   Foundation::{EventRegistrationToken, TimeSpan, TypedEventHandler, Uri},
   Media::{
-    MediaPlaybackStatus, MediaPlaybackType, Playback::MediaPlayer as WindowsMediaPlayer,
-    PlaybackPositionChangeRequestedEventArgs, SystemMediaTransportControls,
+    AutoRepeatModeChangeRequestedEventArgs, MediaPlaybackAutoRepeatMode, MediaPlaybackStatus,
+    MediaPlaybackType, Playback::MediaPlayer as WindowsMediaPlayer,
+    PlaybackPositionChangeRequestedEventArgs, PlaybackRateChangeRequestedEventArgs,
+    ShuffleEnabledChangeRequestedEventArgs, SystemMediaTransportControls,
     SystemMediaTransportControlsButton, SystemMediaTransportControlsButtonPressedEventArgs,
     SystemMediaTransportControlsTimelineProperties,
   },
   Storage::{StorageFile, Streams::RandomAccessStreamReference},
 };
 
+use crate::backend::{self, MediaPlayerBackend};
+use crate::error::{self, XosmsError, XosmsErrorCode};
+
 #[napi]
 #[derive(Debug)]
 pub enum MediaPlayerThumbnailType {
@@ -42,6 +53,199 @@ pub enum MediaPlayerPlaybackStatus {
   Stopped = 3,
 }
 
+#[napi]
+#[derive(Debug)]
+pub enum MediaPlayerRepeatMode {
+  Unknown = -1,
+  None = 1,
+  Track = 2,
+  Playlist = 3,
+}
+
+#[napi]
+#[derive(Debug)]
+pub enum MediaPlayerPlaylistOrdering {
+  Unknown = -1,
+  Alphabetical = 1,
+  CreationDate = 2,
+  ModifiedDate = 3,
+  LastPlayDate = 4,
+  UserDefined = 5,
+}
+
+#[napi]
+#[derive(Debug)]
+pub enum MediaPlayerConnectionState {
+  Disconnected = 1,
+  Connected = 2,
+  Reconnecting = 3,
+}
+
+#[napi]
+#[derive(Debug, PartialEq, Eq)]
+pub enum MediaPlayerBusType {
+  Session = 1,
+  System = 2,
+  /// Message bus listening on the bus address, in the format of `DBUS_SESSION_BUS_ADDRESS`
+  Address = 3,
+  /// Peer-to-peer connection to the bus address, without a message bus owning the service name
+  Private = 4,
+}
+
+#[napi(object)]
+#[derive(Clone)]
+pub struct MediaPlayerOptions {
+  /// Backend to use, defaults to the XOSMS_BACKEND environment variable or else Native. The bus options are ignored by the Mock backend
+  pub backend: Option<MediaPlayerBackend>,
+  /// Bus to serve the media player on, defaults to Session. Only used on Linux
+  pub bus_type: Option<MediaPlayerBusType>,
+  /// Address such as `unix:path=/run/user/1000/bus`, required by the Address and Private bus types
+  pub bus_address: Option<String>,
+}
+
+#[napi(object)]
+#[derive(Clone)]
+pub struct MediaPlayerTrack {
+  /// Unique id of the track within the track list
+  pub track_id: String,
+  /// Title of the track
+  pub title: Option<String>,
+  /// Artist of the track
+  pub artist: Option<String>,
+  /// Album title of the track
+  pub album_title: Option<String>,
+  /// Duration of the track in seconds
+  pub duration: Option<f64>,
+  /// Thumbnail uri of the track
+  pub thumbnail: Option<String>,
+}
+
+#[napi(object)]
+#[derive(Clone)]
+pub struct MediaPlayerPlaylist {
+  /// Unique id of the playlist
+  pub playlist_id: String,
+  /// Name of the playlist
+  pub name: String,
+  /// Icon uri of the playlist
+  pub icon: Option<String>,
+  /// Creation date of the playlist in milliseconds since the unix epoch
+  pub creation_date: Option<f64>,
+  /// Last modified date of the playlist in milliseconds since the unix epoch
+  pub modified_date: Option<f64>,
+  /// Last played date of the playlist in milliseconds since the unix epoch
+  pub last_play_date: Option<f64>,
+}
+
+#[napi(object)]
+#[derive(Clone)]
+pub struct MediaPlayerTimelineOptions {
+  /// Start time of the media in seconds, defaults to 0
+  pub start_time: Option<f64>,
+  /// Earliest position in seconds which can be seeked to, defaults to the start time
+  pub min_seek_time: Option<f64>,
+  /// Latest position in seconds which can be seeked to, defaults to the duration
+  pub max_seek_time: Option<f64>,
+  /// Whether to emit a seeked signal when the position jumps away from the expected position, defaults to true
+  pub detect_seek: Option<bool>,
+  /// Amount of seconds the position may differ from the expected position before it is considered a seek, defaults to 1
+  pub seek_threshold: Option<f64>,
+}
+
+#[napi(object)]
+#[derive(Clone)]
+pub struct MediaPlayerActivateOptions {
+  /// Whether another process may take over the service name, defaults to false
+  pub allow_replacement: Option<bool>,
+  /// Whether to take over the service name if its current owner allows replacement, defaults to true
+  pub replace_existing: Option<bool>,
+  /// Whether to wait in the queue for the service name when it is taken instead of failing, defaults to false
+  pub queue: Option<bool>,
+  /// Whether to fall back to the service name suffixed with `.instance<pid>` when it is taken, defaults to false
+  pub instance_fallback: Option<bool>,
+  /// Seconds to wait for the session bus before failing, defaults to 1
+  pub timeout: Option<f64>,
+}
+
+#[napi(object)]
+#[derive(Clone)]
+pub struct MediaPlayerActivation {
+  /// The service name which was acquired, or queued for
+  pub service_name: String,
+  /// Whether the service name was queued for as it is owned by another process
+  pub queued: bool,
+}
+
+#[napi(object)]
+#[derive(Clone)]
+pub struct MediaPlayerCapabilities {
+  /// Play button enabled state
+  pub play_button_enabled: Option<bool>,
+  /// Pause button enabled state
+  pub pause_button_enabled: Option<bool>,
+  /// Stop button enabled state
+  pub stop_button_enabled: Option<bool>,
+  /// Previous button enabled state
+  pub previous_button_enabled: Option<bool>,
+  /// Next button enabled state
+  pub next_button_enabled: Option<bool>,
+  /// Seek enabled state
+  pub seek_enabled: Option<bool>,
+}
+
+#[napi(object)]
+#[derive(Clone)]
+pub struct MediaPlayerPublishedState {
+  /// The service name which was acquired
+  pub service_name: String,
+  /// Playback status
+  pub playback_status: MediaPlayerPlaybackStatus,
+  /// Media title
+  pub title: String,
+  /// Media artists
+  pub artists: Vec<String>,
+  /// Media album title
+  pub album_title: String,
+  /// Track id
+  pub track_id: String,
+  /// Position in seconds
+  pub position: f64,
+  /// Playback rate
+  pub playback_rate: f64,
+  /// Volume
+  pub volume: f64,
+  /// Repeat mode
+  pub repeat_mode: MediaPlayerRepeatMode,
+  /// Shuffle state
+  pub shuffle: bool,
+  /// Button and seek enabled states
+  pub capabilities: MediaPlayerCapabilities,
+}
+
+#[napi(object, object_to_js = false)]
+struct MediaPlayerState {
+  /// Media title
+  pub title: Option<String>,
+  /// Media artists
+  pub artists: Option<Vec<String>>,
+  /// Media album title
+  pub album_title: Option<String>,
+  /// Track id of the media
+  pub track_id: Option<String>,
+  /// Duration of the media in seconds
+  pub duration: Option<f64>,
+  /// Position of the media in seconds
+  pub position: Option<f64>,
+  /// Thumbnail of the media
+  pub thumbnail: Option<ClassInstance<MediaPlayerThumbnail>>,
+  /// Playback status
+  pub playback_status: Option<MediaPlayerPlaybackStatus>,
+  /// Button enabled states
+  pub capabilities: Option<MediaPlayerCapabilities>,
+  /// Timeline options applied with the duration and position, defaults to the options of the last timeline update
+  pub timeline_options: Option<MediaPlayerTimelineOptions>,
+}
+
 #[napi]
 struct MediaPlayerThumbnail {
   thumbnail_type: MediaPlayerThumbnailType,
@@ -69,19 +273,13 @@ impl MediaPlayerThumbnail {
                 stream_ref,
               });
             } else {
-              return Err(napi::Error::from_reason(
-                stream_ref_result.unwrap_err().message(),
-              ));
+              return Err(XosmsError::from(&stream_ref_result.unwrap_err()).into());
             }
           } else {
-            return Err(napi::Error::from_reason(
-              file_async_operation_result.unwrap_err().message(),
-            ));
+            return Err(XosmsError::from(&file_async_operation_result.unwrap_err()).into());
           }
         } else {
-          return Err(napi::Error::from_reason(
-            file_async_operation.unwrap_err().message(),
-          ));
+          return Err(XosmsError::from(&file_async_operation.unwrap_err()).into());
         }
       }
       MediaPlayerThumbnailType::Uri => {
@@ -94,18 +292,24 @@ impl MediaPlayerThumbnail {
               stream_ref,
             });
           } else {
-            return Err(napi::Error::from_reason(
-              stream_ref_result.unwrap_err().message(),
-            ));
+            return Err(XosmsError::from(&stream_ref_result.unwrap_err()).into());
           }
         } else {
-          return Err(napi::Error::from_reason(uri_result.unwrap_err().message()));
+          return Err(XosmsError::from(&uri_result.unwrap_err()).into());
         }
       }
-      _ => Err(napi::Error::from_reason(format!(
-        "{:?} is not a valid MediaPlayerThumbnailType to create",
-        thumbnail_type
-      ))),
+      _ => Err(
+        XosmsError::new(
+          XosmsErrorCode::XOSMS_INVALID_ENUM,
+          format!(
+            "{:?} is not a valid MediaPlayerThumbnailType to create",
+            thumbnail_type
+          ),
+        )
+        .detail("value", thumbnail_type as i32)
+        .detail("enum", "MediaPlayerThumbnailType")
+        .into(),
+      ),
     }
   }
 
@@ -121,23 +325,56 @@ struct MediaPlayer {
   player: WindowsMediaPlayer,
   smtc_button_pressed_registration: EventRegistrationToken,
   smtc_playback_position_changed_registration: EventRegistrationToken,
+  smtc_auto_repeat_mode_changed_registration: EventRegistrationToken,
+  smtc_shuffle_enabled_changed_registration: EventRegistrationToken,
+  smtc_playback_rate_changed_registration: EventRegistrationToken,
   button_pressed_listeners:
     Arc<DashMap<usize, ThreadsafeFunction<String, ErrorStrategy::CalleeHandled>>>,
   playback_position_changed_listeners:
     Arc<DashMap<usize, ThreadsafeFunction<f64, ErrorStrategy::CalleeHandled>>>,
+  repeat_mode_changed_listeners:
+    Arc<DashMap<usize, ThreadsafeFunction<MediaPlayerRepeatMode, ErrorStrategy::CalleeHandled>>>,
+  shuffle_changed_listeners:
+    Arc<DashMap<usize, ThreadsafeFunction<bool, ErrorStrategy::CalleeHandled>>>,
+  rate_changed_listeners:
+    Arc<DashMap<usize, ThreadsafeFunction<f64, ErrorStrategy::CalleeHandled>>>,
+  timeline_properties: Option<SystemMediaTransportControlsTimelineProperties>,
+  /// Options of the last timeline update, reused by set_state when no options are provided
+  timeline_options: Option<MediaPlayerTimelineOptions>,
+  /// Whether the transport controls are only kept in memory and never enabled
+  mock: bool,
+  /// Activation state of the mock backend, which does not enable the transport controls
+  mock_active: AtomicBool,
 }
 
 #[napi]
 impl MediaPlayer {
   #[napi(constructor)]
   #[allow(dead_code)]
-  pub fn new(service_name: String, _identity: String) -> napi::Result<Self> {
+  pub fn new(
+    env: Env,
+    service_name: String,
+    _identity: String,
+    options: Option<MediaPlayerOptions>,
+  ) -> napi::Result<Self> {
+    error::register_env(env);
+    let mock = backend::resolve_backend(options.and_then(|options| options.backend))?
+      == MediaPlayerBackend::Mock;
     let button_pressed_listeners: Arc<
       DashMap<usize, ThreadsafeFunction<String, ErrorStrategy::CalleeHandled>>,
     > = Arc::new(DashMap::new());
     let playback_position_changed_listeners: Arc<
       DashMap<usize, ThreadsafeFunction<f64, ErrorStrategy::CalleeHandled>>,
     > = Arc::new(DashMap::new());
+    let repeat_mode_changed_listeners: Arc<
+      DashMap<usize, ThreadsafeFunction<MediaPlayerRepeatMode, ErrorStrategy::CalleeHandled>>,
+    > = Arc::new(DashMap::new());
+    let shuffle_changed_listeners: Arc<
+      DashMap<usize, ThreadsafeFunction<bool, ErrorStrategy::CalleeHandled>>,
+    > = Arc::new(DashMap::new());
+    let rate_changed_listeners: Arc<
+      DashMap<usize, ThreadsafeFunction<f64, ErrorStrategy::CalleeHandled>>,
+    > = Arc::new(DashMap::new());
     let player_result = WindowsMediaPlayer::new();
     return match player_result {
       Ok(player) => {
@@ -227,78 +464,380 @@ impl MediaPlayer {
             if let Ok(playback_position_changed_registration) =
               playback_position_changed_registration_result
             {
-              let du_result = smtc.DisplayUpdater();
-              if let Ok(du) = du_result {
-                let set_app_media_id_result = du.SetAppMediaId(&HSTRING::from(service_name));
-                if set_app_media_id_result.is_ok() {
-                  return Ok(Self {
-                    player,
-                    button_pressed_listeners,
-                    playback_position_changed_listeners,
-                    smtc_button_pressed_registration: button_pressed_registration,
-                    smtc_playback_position_changed_registration:
-                      playback_position_changed_registration,
+              let smtc_repeat_mode_changed_listeners = repeat_mode_changed_listeners.clone();
+              let handler = TypedEventHandler::<
+                SystemMediaTransportControls,
+                AutoRepeatModeChangeRequestedEventArgs,
+              >::new(move |_sender, args| {
+                if let Some(args) = args {
+                  let smtc_requested_auto_repeat_mode_result = args.RequestedAutoRepeatMode();
+                  if let Ok(requested_auto_repeat_mode) = smtc_requested_auto_repeat_mode_result {
+                    let repeat_mode = match requested_auto_repeat_mode {
+                      MediaPlaybackAutoRepeatMode::Track => MediaPlayerRepeatMode::Track,
+                      MediaPlaybackAutoRepeatMode::List => MediaPlayerRepeatMode::Playlist,
+                      _ => MediaPlayerRepeatMode::None,
+                    };
+                    for listener in smtc_repeat_mode_changed_listeners.iter() {
+                      listener.call(Ok(repeat_mode), ThreadsafeFunctionCallMode::NonBlocking);
+                    }
+                  }
+                }
+
+                Ok(())
+              });
+
+              let auto_repeat_mode_changed_registration_result =
+                smtc.AutoRepeatModeChangeRequested(&handler);
+              if let Ok(auto_repeat_mode_changed_registration) =
+                auto_repeat_mode_changed_registration_result
+              {
+                let smtc_shuffle_changed_listeners = shuffle_changed_listeners.clone();
+                let handler = TypedEventHandler::<
+                  SystemMediaTransportControls,
+                  ShuffleEnabledChangeRequestedEventArgs,
+                >::new(move |_sender, args| {
+                  if let Some(args) = args {
+                    let smtc_requested_shuffle_enabled_result = args.RequestedShuffleEnabled();
+                    if let Ok(requested_shuffle_enabled) = smtc_requested_shuffle_enabled_result {
+                      for listener in smtc_shuffle_changed_listeners.iter() {
+                        listener.call(
+                          Ok(requested_shuffle_enabled),
+                          ThreadsafeFunctionCallMode::NonBlocking,
+                        );
+                      }
+                    }
+                  }
+
+                  Ok(())
+                });
+
+                let shuffle_enabled_changed_registration_result =
+                  smtc.ShuffleEnabledChangeRequested(&handler);
+                if let Ok(shuffle_enabled_changed_registration) =
+                  shuffle_enabled_changed_registration_result
+                {
+                  let smtc_rate_changed_listeners = rate_changed_listeners.clone();
+                  let handler = TypedEventHandler::<
+                    SystemMediaTransportControls,
+                    PlaybackRateChangeRequestedEventArgs,
+                  >::new(move |_sender, args| {
+                    if let Some(args) = args {
+                      let smtc_requested_playback_rate_result = args.RequestedPlaybackRate();
+                      if let Ok(requested_playback_rate) = smtc_requested_playback_rate_result {
+                        for listener in smtc_rate_changed_listeners.iter() {
+                          listener.call(
+                            Ok(requested_playback_rate),
+                            ThreadsafeFunctionCallMode::NonBlocking,
+                          );
+                        }
+                      }
+                    }
+
+                    Ok(())
                   });
+
+                  let playback_rate_changed_registration_result =
+                    smtc.PlaybackRateChangeRequested(&handler);
+                  if let Ok(playback_rate_changed_registration) =
+                    playback_rate_changed_registration_result
+                  {
+                    let du_result = smtc.DisplayUpdater();
+                    if let Ok(du) = du_result {
+                      let set_app_media_id_result = du.SetAppMediaId(&HSTRING::from(service_name));
+                      if set_app_media_id_result.is_ok() {
+                        return Ok(Self {
+                          player,
+                          button_pressed_listeners,
+                          playback_position_changed_listeners,
+                          repeat_mode_changed_listeners,
+                          shuffle_changed_listeners,
+                          rate_changed_listeners,
+                          timeline_properties: None,
+                          timeline_options: None,
+                          mock,
+                          mock_active: AtomicBool::new(false),
+                          smtc_button_pressed_registration: button_pressed_registration,
+                          smtc_playback_position_changed_registration:
+                            playback_position_changed_registration,
+                          smtc_auto_repeat_mode_changed_registration:
+                            auto_repeat_mode_changed_registration,
+                          smtc_shuffle_enabled_changed_registration:
+                            shuffle_enabled_changed_registration,
+                          smtc_playback_rate_changed_registration:
+                            playback_rate_changed_registration,
+                        });
+                      } else {
+                        return Err(XosmsError::from(&set_app_media_id_result.unwrap_err()).into());
+                      }
+                    } else {
+                      return Err(XosmsError::from(&du_result.unwrap_err()).into());
+                    }
+                  } else {
+                    return Err(
+                      XosmsError::from(&playback_rate_changed_registration_result.unwrap_err())
+                        .into(),
+                    );
+                  }
                 } else {
-                  return Err(napi::Error::from_reason(
-                    set_app_media_id_result.unwrap_err().message(),
-                  ));
+                  return Err(
+                    XosmsError::from(&shuffle_enabled_changed_registration_result.unwrap_err())
+                      .into(),
+                  );
                 }
               } else {
-                return Err(napi::Error::from_reason(du_result.unwrap_err().message()));
+                return Err(
+                  XosmsError::from(&auto_repeat_mode_changed_registration_result.unwrap_err())
+                    .into(),
+                );
               }
             } else {
-              return Err(napi::Error::from_reason(
-                playback_position_changed_registration_result
-                  .unwrap_err()
-                  .message(),
-              ));
+              return Err(
+                XosmsError::from(&playback_position_changed_registration_result.unwrap_err())
+                  .into(),
+              );
             }
           } else {
-            return Err(napi::Error::from_reason(
-              button_pressed_registration_result.unwrap_err().message(),
-            ));
+            return Err(XosmsError::from(&button_pressed_registration_result.unwrap_err()).into());
           }
         }
 
-        Err(napi::Error::from_reason(smtc_result.unwrap_err().message()))
+        Err(XosmsError::from(&smtc_result.unwrap_err()).into())
       }
-      Err(error) => Err(napi::Error::from_reason(error.message())),
+      Err(error) => Err(XosmsError::from(&error).into()),
     };
   }
 
   /// Activates the MediaPlayer allowing the operating system to see and use it
+  ///
+  /// Returns the service name which was acquired, or queued for when queueing is enabled and the name is taken. A 'nameacquired' event is emitted once a queued name is acquired.
   #[napi]
   #[allow(dead_code)]
-  pub fn activate(&self) -> napi::Result<()> {
+  pub fn activate(&self, _options: Option<MediaPlayerActivateOptions>) -> napi::Result<String> {
     let smtc_result = self.player.SystemMediaTransportControls();
     match smtc_result {
       Ok(smtc) => {
-        let set_enabled_result = smtc.SetIsEnabled(true);
-        match set_enabled_result {
-          Err(error) => return Err(napi::Error::from_reason(error.message())),
-          Ok(()) => Ok(()),
+        if self.mock {
+          self.mock_active.store(true, Ordering::SeqCst);
+        } else {
+          let set_enabled_result = smtc.SetIsEnabled(true);
+          if let Err(error) = set_enabled_result {
+            return Err(XosmsError::from(&error).into());
+          }
+        }
+
+        let du_result = smtc.DisplayUpdater();
+        match du_result {
+          Ok(du) => {
+            let get_app_media_id_result = du.AppMediaId();
+            match get_app_media_id_result {
+              Err(error) => return Err(XosmsError::from(&error).into()),
+              Ok(app_media_id) => Ok(app_media_id.to_string()),
+            }
+          }
+          Err(error) => Err(XosmsError::from(&error).into()),
         }
       }
-      Err(error) => return Err(napi::Error::from_reason(error.message())),
+      Err(error) => return Err(XosmsError::from(&error).into()),
     }
   }
 
+  /// Activates the MediaPlayer without blocking while waiting for the session bus
+  ///
+  /// Resolves with the service name which was acquired, or queued for, once the session bus responds.
+  #[napi(ts_return_type = "Promise<MediaPlayerActivation>")]
+  #[allow(dead_code)]
+  pub fn activate_async(
+    &self,
+    options: Option<MediaPlayerActivateOptions>,
+  ) -> napi::Result<AsyncTask<ActivateTask>> {
+    let service_name = self.activate(options)?;
+    Ok(AsyncTask::new(ActivateTask {
+      activation: Some(MediaPlayerActivation {
+        service_name,
+        queued: false,
+      }),
+    }))
+  }
+
+  /// Deactivates the MediaPlayer without blocking while waiting for the session bus
+  ///
+  /// Resolves once the service name has been released, waiting for at most the timeout in seconds which defaults to 1.
+  #[napi(ts_return_type = "Promise<void>")]
+  #[allow(dead_code)]
+  pub fn deactivate_async(&self, _timeout: Option<f64>) -> napi::Result<AsyncTask<DeactivateTask>> {
+    self.deactivate()?;
+    Ok(AsyncTask::new(DeactivateTask {}))
+  }
+
   /// Deactivates the MediaPlayer denying the operating system to see and use it
   #[napi]
   #[allow(dead_code)]
   pub fn deactivate(&self) -> napi::Result<()> {
+    if self.mock {
+      self.mock_active.store(false, Ordering::SeqCst);
+      return Ok(());
+    }
+
     let smtc_result = self.player.SystemMediaTransportControls();
     match smtc_result {
       Ok(smtc) => {
         let set_enabled_result = smtc.SetIsEnabled(false);
         match set_enabled_result {
-          Err(error) => return Err(napi::Error::from_reason(error.message())),
+          Err(error) => return Err(XosmsError::from(&error).into()),
           Ok(()) => Ok(()),
         }
       }
-      Err(error) => return Err(napi::Error::from_reason(error.message())),
+      Err(error) => return Err(XosmsError::from(&error).into()),
+    }
+  }
+
+  /// Gets the state of the connection to the session bus
+  ///
+  /// While reconnecting the media player is not visible to the operating system. Its name is requested again and its state re-broadcast once the bus is back.
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_connection_state(&self) -> napi::Result<MediaPlayerConnectionState> {
+    if self.mock {
+      return match self.mock_active.load(Ordering::SeqCst) {
+        true => Ok(MediaPlayerConnectionState::Connected),
+        false => Ok(MediaPlayerConnectionState::Disconnected),
+      };
+    }
+
+    let smtc_result = self.player.SystemMediaTransportControls();
+    match smtc_result {
+      Ok(smtc) => {
+        let get_is_enabled_result = smtc.IsEnabled();
+        match get_is_enabled_result {
+          Err(error) => return Err(XosmsError::from(&error).into()),
+          Ok(true) => Ok(MediaPlayerConnectionState::Connected),
+          Ok(false) => Ok(MediaPlayerConnectionState::Disconnected),
+        }
+      }
+      Err(error) => Err(XosmsError::from(&error).into()),
+    }
+  }
+
+  /// Simulates a media services button press, only available with the mock backend
+  ///
+  /// Presses are handled like they are from the operating system, so they are ignored while the media player is not activated or the button is disabled. 'playpause' is always ignored as it is never pressed on Windows.
+  #[napi]
+  #[allow(dead_code)]
+  pub fn simulate_button(
+    &self,
+    #[napi(ts_arg_type = "'play' | 'pause' | 'playpause' | 'stop' | 'next' | 'previous'")]
+    button: String,
+  ) -> napi::Result<()> {
+    if !self.mock {
+      return Err(backend::mock_only("simulateButton").into());
+    }
+    let enabled = match button.as_str() {
+      "play" => self.get_play_button_enabled()?,
+      "pause" => self.get_pause_button_enabled()?,
+      "playpause" => false,
+      "stop" => self.get_stop_button_enabled()?,
+      "next" => self.get_next_button_enabled()?,
+      "previous" => self.get_previous_button_enabled()?,
+      _ => {
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+            format!("{} is not a media services button", button),
+          )
+          .detail("value", button)
+          .into(),
+        )
+      }
+    };
+    if !enabled || !self.mock_active.load(Ordering::SeqCst) {
+      return Ok(());
+    }
+
+    for listener in self.button_pressed_listeners.iter() {
+      listener.call(Ok(button.clone()), ThreadsafeFunctionCallMode::NonBlocking);
+    }
+
+    Ok(())
+  }
+
+  /// Simulates the media service requesting a position change in seconds for the current track, only available with the mock backend
+  ///
+  /// The request is ignored while the media player is not activated.
+  #[napi]
+  #[allow(dead_code)]
+  pub fn simulate_position_change(&self, position: f64) -> napi::Result<()> {
+    if !self.mock {
+      return Err(backend::mock_only("simulatePositionChange").into());
+    }
+    validate_simulated_seconds(position)?;
+    if position < 0.0 || !self.mock_active.load(Ordering::SeqCst) {
+      return Ok(());
+    }
+
+    for listener in self.playback_position_changed_listeners.iter() {
+      listener.call(Ok(position), ThreadsafeFunctionCallMode::NonBlocking);
+    }
+
+    Ok(())
+  }
+
+  /// Simulates the media service requesting a seek by the offset in seconds from the current position, only available with the mock backend
+  ///
+  /// The request is always ignored as relative seeks are never requested on Windows.
+  #[napi]
+  #[allow(dead_code)]
+  pub fn simulate_seek(&self, offset: f64) -> napi::Result<()> {
+    if !self.mock {
+      return Err(backend::mock_only("simulateSeek").into());
+    }
+    validate_simulated_seconds(offset)?;
+
+    Ok(())
+  }
+
+  /// Gets a snapshot of the state visible to the operating system, only available with the mock backend
+  ///
+  /// Returns null while the media player is not activated.
+  #[napi]
+  #[allow(dead_code)]
+  pub fn published_state(&self) -> napi::Result<Option<MediaPlayerPublishedState>> {
+    if !self.mock {
+      return Err(backend::mock_only("publishedState").into());
+    }
+    if !self.mock_active.load(Ordering::SeqCst) {
+      return Ok(None);
     }
+
+    let du_result = self
+      .player
+      .SystemMediaTransportControls()
+      .and_then(|smtc| smtc.DisplayUpdater());
+    let service_name = match du_result.and_then(|du| du.AppMediaId()) {
+      Ok(app_media_id) => app_media_id.to_string(),
+      Err(error) => return Err(XosmsError::from(&error).into()),
+    };
+
+    Ok(Some(MediaPlayerPublishedState {
+      service_name,
+      playback_status: self.get_playback_status()?,
+      title: self.get_title()?,
+      artists: self.get_artists()?,
+      album_title: self.get_album_title()?,
+      track_id: self.get_track_id()?,
+      position: self.get_position()?,
+      playback_rate: self.get_playback_rate()?,
+      volume: self.get_volume()?,
+      repeat_mode: self.get_repeat_mode()?,
+      shuffle: self.get_shuffle()?,
+      capabilities: MediaPlayerCapabilities {
+        play_button_enabled: Some(self.get_play_button_enabled()?),
+        pause_button_enabled: Some(self.get_pause_button_enabled()?),
+        stop_button_enabled: Some(self.get_stop_button_enabled()?),
+        previous_button_enabled: Some(self.get_previous_button_enabled()?),
+        next_button_enabled: Some(self.get_next_button_enabled()?),
+        seek_enabled: Some(self.get_seek_enabled()?),
+      },
+    }))
   }
 
   /// Adds an event listener to the MediaPlayer
@@ -306,12 +845,30 @@ impl MediaPlayer {
   /// 'buttonpressed' - Emitted when a media services button is pressed
   /// 'positionchanged' - Emitted when the media service requests a position change
   /// 'positionseeked' - Emitted when the media service requests a forward or backward position seek from current position
+  /// 'trackgoto' - Emitted when the media service requests to skip to a track in the track list
+  /// 'trackadd' - Emitted when the media service requests a uri to be added to the track list
+  /// 'trackremove' - Emitted when the media service requests a track to be removed from the track list
+  /// 'playlistactivated' - Emitted when the media service requests a playlist to be activated
+  /// 'repeatmodechanged' - Emitted when the media service requests a repeat mode change
+  /// 'shufflechanged' - Emitted when the media service requests shuffle to be enabled or disabled
+  /// 'volumechanged' - Emitted when the media service requests a volume change
+  /// 'ratechanged' - Emitted when the media service requests a playback rate change
+  /// 'raise' - Emitted when the media service requests the media player to be brought to the front
+  /// 'quit' - Emitted when the media service requests the media player to quit
+  /// 'openuri' - Emitted when the media service requests a uri to be opened
+  /// 'fullscreenchanged' - Emitted when the media service requests fullscreen to be enabled or disabled
+  /// 'tick' - Emitted every tick interval with the current position while playing
+  /// 'ended' - Emitted when the position reaches the duration while playing, requires a tick interval
+  /// 'busdisconnected' - Emitted when the connection to the session bus is lost while active
+  /// 'busreconnected' - Emitted when the media player is visible again after the connection to the session bus was restored
+  /// 'nameacquired' - Emitted with the service name when a queued for service name is acquired
+  /// 'namelost' - Emitted with the service name when another process takes over the service name
   #[napi]
   #[allow(dead_code)]
   pub fn add_event_listener(
     &mut self,
     env: Env,
-    #[napi(ts_arg_type = "'buttonpressed' | 'positionchanged' | 'positionseeked'")] event_name: String,
+    #[napi(ts_arg_type = "'buttonpressed' | 'positionchanged' | 'positionseeked' | 'trackgoto' | 'trackadd' | 'trackremove' | 'playlistactivated' | 'repeatmodechanged' | 'shufflechanged' | 'volumechanged' | 'ratechanged' | 'raise' | 'quit' | 'openuri' | 'fullscreenchanged' | 'tick' | 'ended' | 'busdisconnected' | 'busreconnected' | 'nameacquired' | 'namelost'")] event_name: String,
     callback: JsFunction,
   ) -> napi::Result<()> {
     let callback_ptr = unsafe { callback.raw() as usize };
@@ -342,6 +899,32 @@ impl MediaPlayer {
             .insert(callback_ptr, threadsafe_callback);
         }
       }
+      "repeatmodechanged" => {
+        if let Entry::Vacant(entry) = self.repeat_mode_changed_listeners.entry(callback_ptr) {
+          let mut threadsafe_callback = callback.create_threadsafe_function(0, |ctx| {
+            ctx.env.create_int32(ctx.value as i32).map(|v| vec![v])
+          })?;
+          threadsafe_callback.unref(&env)?;
+          entry.insert(threadsafe_callback);
+        }
+      }
+      "shufflechanged" => {
+        if let Entry::Vacant(entry) = self.shuffle_changed_listeners.entry(callback_ptr) {
+          let mut threadsafe_callback = callback
+            .create_threadsafe_function(0, |ctx| ctx.env.get_boolean(ctx.value).map(|v| vec![v]))?;
+          threadsafe_callback.unref(&env)?;
+          entry.insert(threadsafe_callback);
+        }
+      }
+      "ratechanged" => {
+        if let Entry::Vacant(entry) = self.rate_changed_listeners.entry(callback_ptr) {
+          let mut threadsafe_callback = callback.create_threadsafe_function(0, |ctx| {
+            ctx.env.create_double(ctx.value).map(|v| vec![v])
+          })?;
+          threadsafe_callback.unref(&env)?;
+          entry.insert(threadsafe_callback);
+        }
+      }
       _ => {}
     };
 
@@ -353,7 +936,7 @@ impl MediaPlayer {
   #[allow(dead_code)]
   pub fn remove_event_listener(
     &mut self,
-    #[napi(ts_arg_type = "'buttonpressed' | 'positionchanged' | 'positionseeked'")] event_name: String,
+    #[napi(ts_arg_type = "'buttonpressed' | 'positionchanged' | 'positionseeked' | 'trackgoto' | 'trackadd' | 'trackremove' | 'playlistactivated' | 'repeatmodechanged' | 'shufflechanged' | 'volumechanged' | 'ratechanged' | 'raise' | 'quit' | 'openuri' | 'fullscreenchanged' | 'tick' | 'ended' | 'busdisconnected' | 'busreconnected' | 'nameacquired' | 'namelost'")] event_name: String,
     callback: JsFunction,
   ) -> napi::Result<()> {
     let callback_ptr = unsafe { callback.raw() as usize };
@@ -374,6 +957,15 @@ impl MediaPlayer {
             .remove(&callback_ptr);
         }
       }
+      "repeatmodechanged" => {
+        self.repeat_mode_changed_listeners.remove(&callback_ptr);
+      }
+      "shufflechanged" => {
+        self.shuffle_changed_listeners.remove(&callback_ptr);
+      }
+      "ratechanged" => {
+        self.rate_changed_listeners.remove(&callback_ptr);
+      }
       _ => {}
     };
 
@@ -388,7 +980,7 @@ impl MediaPlayer {
   pub fn on(
     &mut self,
     env: Env,
-    #[napi(ts_arg_type = "'buttonpressed' | 'positionchanged' | 'positionseeked'")] event_name: String,
+    #[napi(ts_arg_type = "'buttonpressed' | 'positionchanged' | 'positionseeked' | 'trackgoto' | 'trackadd' | 'trackremove' | 'playlistactivated' | 'repeatmodechanged' | 'shufflechanged' | 'volumechanged' | 'ratechanged' | 'raise' | 'quit' | 'openuri' | 'fullscreenchanged' | 'tick' | 'ended' | 'busdisconnected' | 'busreconnected' | 'nameacquired' | 'namelost'")] event_name: String,
     callback: JsFunction,
   ) -> napi::Result<()> {
     self.add_event_listener(env, event_name, callback)
@@ -401,7 +993,7 @@ impl MediaPlayer {
   #[allow(dead_code)]
   pub fn off(
     &mut self,
-    #[napi(ts_arg_type = "'buttonpressed' | 'positionchanged' | 'positionseeked'")] event_name: String,
+    #[napi(ts_arg_type = "'buttonpressed' | 'positionchanged' | 'positionseeked' | 'trackgoto' | 'trackadd' | 'trackremove' | 'playlistactivated' | 'repeatmodechanged' | 'shufflechanged' | 'volumechanged' | 'ratechanged' | 'raise' | 'quit' | 'openuri' | 'fullscreenchanged' | 'tick' | 'ended' | 'busdisconnected' | 'busreconnected' | 'nameacquired' | 'namelost'")] event_name: String,
     callback: JsFunction,
   ) -> napi::Result<()> {
     self.remove_event_listener(event_name, callback)
@@ -418,15 +1010,146 @@ impl MediaPlayer {
         if let Ok(du) = du_result {
           let update_result = du.Update();
           match update_result {
-            Err(error) => return Err(napi::Error::from_reason(error.message())),
+            Err(error) => return Err(XosmsError::from(&error).into()),
             Ok(()) => Ok(()),
           }
         } else {
-          Err(napi::Error::from_reason(du_result.unwrap_err().message()))
+          Err(XosmsError::from(&du_result.unwrap_err()).into())
         }
       }
-      Err(error) => Err(napi::Error::from_reason(error.message())),
+      Err(error) => Err(XosmsError::from(&error).into()),
+    }
+  }
+
+  /// Immediately emits the accumulated changes without waiting for the auto flush debounce window
+  #[napi]
+  #[allow(dead_code)]
+  pub fn flush_now(&self) -> napi::Result<()> {
+    self.update()
+  }
+
+  /// Gets the auto flush debounce window in seconds
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_auto_flush_debounce(&self) -> napi::Result<Option<f64>> {
+    Ok(None)
+  }
+
+  /// Sets the auto flush debounce window in seconds
+  ///
+  /// When set, accumulated changes are emitted automatically once the debounce window after the first change has passed. When null, changes are only emitted on update.
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_auto_flush_debounce(&mut self, auto_flush_debounce: Option<f64>) -> napi::Result<()> {
+    if let Some(auto_flush_debounce) = auto_flush_debounce {
+      if auto_flush_debounce < 0.0 || Duration::try_from_secs_f64(auto_flush_debounce).is_err() {
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+            format!(
+              "{} is not a valid auto flush debounce window, it must be a finite number of 0 or greater",
+              auto_flush_debounce
+            ),
+          )
+          .detail("value", auto_flush_debounce)
+          .into(),
+        );
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Sets multiple properties at once
+  ///
+  /// Every provided value is validated before any of them are applied so a validation failure leaves the state untouched. Omitted values are left unchanged.
+  #[napi]
+  #[allow(dead_code)]
+  pub fn set_state(&mut self, state: MediaPlayerState) -> napi::Result<()> {
+    if matches!(
+      state.playback_status,
+      Some(MediaPlayerPlaybackStatus::Unknown)
+    ) {
+      return Err(
+        XosmsError::new(
+          XosmsErrorCode::XOSMS_INVALID_ENUM,
+          format!(
+            "{:?} is not a valid MediaPlayerPlaybackStatus to set",
+            MediaPlayerPlaybackStatus::Unknown
+          ),
+        )
+        .detail("value", MediaPlayerPlaybackStatus::Unknown as i32)
+        .detail("enum", "MediaPlayerPlaybackStatus")
+        .into(),
+      );
+    }
+
+    let timeline = if state.duration.is_some() || state.position.is_some() {
+      let timeline_props = self.timeline_properties.as_ref();
+      let duration = state.duration.unwrap_or_else(|| {
+        timeline_props
+          .and_then(|timeline_props| timeline_props.EndTime().ok())
+          .map(|end_time| Duration::from(end_time).as_secs_f64())
+          .unwrap_or(0.0)
+      });
+      let position = state.position.unwrap_or_else(|| {
+        timeline_props
+          .and_then(|timeline_props| timeline_props.Position().ok())
+          .map(|position| Duration::from(position).as_secs_f64())
+          .unwrap_or(0.0)
+      });
+      let options = state
+        .timeline_options
+        .or_else(|| self.timeline_options.clone());
+      validate_timeline(duration, position, &options)?;
+      Some((duration, position, options))
+    } else {
+      None
+    };
+
+    if let Some(title) = state.title {
+      self.set_title(title)?;
+    }
+    if let Some(artists) = state.artists {
+      self.set_artists(artists)?;
+    }
+    if let Some(album_title) = state.album_title {
+      self.set_album_title(album_title)?;
+    }
+    if let Some(track_id) = state.track_id {
+      self.set_track_id(track_id)?;
+    }
+    if let Some(thumbnail) = &state.thumbnail {
+      self.set_thumbnail(thumbnail)?;
+    }
+    if let Some((duration, position, options)) = timeline {
+      self.set_timeline(duration, position, options)?;
+    }
+    if let Some(playback_status) = state.playback_status {
+      self.set_playback_status(playback_status)?;
+    }
+    if let Some(capabilities) = state.capabilities {
+      if let Some(enabled) = capabilities.play_button_enabled {
+        self.set_play_button_enabled(enabled)?;
+      }
+      if let Some(enabled) = capabilities.pause_button_enabled {
+        self.set_pause_button_enabled(enabled)?;
+      }
+      if let Some(enabled) = capabilities.stop_button_enabled {
+        self.set_stop_button_enabled(enabled)?;
+      }
+      if let Some(enabled) = capabilities.previous_button_enabled {
+        self.set_previous_button_enabled(enabled)?;
+      }
+      if let Some(enabled) = capabilities.next_button_enabled {
+        self.set_next_button_enabled(enabled)?;
+      }
+      if let Some(enabled) = capabilities.seek_enabled {
+        self.set_seek_enabled(enabled)?;
+      }
     }
+
+    Ok(())
   }
 
   /// Sets the thumbnail
@@ -440,80 +1163,193 @@ impl MediaPlayer {
         if let Ok(du) = du_result {
           let set_thumbnail_result = du.SetThumbnail(&thumbnail.stream_ref);
           match set_thumbnail_result {
-            Err(error) => return Err(napi::Error::from_reason(error.message())),
+            Err(error) => return Err(XosmsError::from(&error).into()),
             Ok(()) => Ok(()),
           }
         } else {
-          Err(napi::Error::from_reason(du_result.unwrap_err().message()))
+          Err(XosmsError::from(&du_result.unwrap_err()).into())
         }
       }
-      Err(error) => Err(napi::Error::from_reason(error.message())),
+      Err(error) => Err(XosmsError::from(&error).into()),
     }
   }
 
   /// Sets the timeline data
   ///
-  /// You MUST call this function everytime the position changes in the song. The media service will become out of sync if this is not called enough or cause seeked signals to be emitted to the media service unnecessarily.
+  /// The position is advanced automatically while the playback status is playing, scaled by the playback rate and capped at the duration. You only need to call this function when the position changes in any other way, such as when the track changes. Seeks should be reported with seeked.
+  ///
+  /// A seeked signal is still emitted when the position differs from the expected position by more than the seek threshold, unless seek detection is turned off in the options. A change of duration is treated as a new track and never reported as a seek.
+  ///
+  /// Without options the options of the previous timeline are kept.
   #[napi]
   #[allow(dead_code)]
-  pub fn set_timeline(&mut self, duration: f64, position: f64) -> napi::Result<()> {
-    if duration < 0.0 {
-      return Err(napi::Error::from_reason("Duration cannot be less than 0"));
-    }
-    if position < 0.0 {
-      return Err(napi::Error::from_reason("Position cannot be less than 0"));
-    }
-    if position > duration {
-      return Err(napi::Error::from_reason(
-        "Position cannot be greather than provided duration",
-      ));
-    }
+  pub fn set_timeline(
+    &mut self,
+    duration: f64,
+    position: f64,
+    options: Option<MediaPlayerTimelineOptions>,
+  ) -> napi::Result<()> {
+    let options = options.or_else(|| self.timeline_options.clone());
+    let bounds = validate_timeline(duration, position, &options)?;
 
     let smtc_result = self.player.SystemMediaTransportControls();
     match smtc_result {
       Ok(smtc) => {
         let timeline_props = SystemMediaTransportControlsTimelineProperties::new().unwrap();
         let set_start_time_result =
-          timeline_props.SetStartTime(TimeSpan::from(Duration::from_secs_f64(0.0)));
+          timeline_props.SetStartTime(TimeSpan::from(Duration::from_secs_f64(bounds.start_time)));
         let set_end_time_result =
           timeline_props.SetEndTime(TimeSpan::from(Duration::from_secs_f64(duration)));
         let set_position_result =
           timeline_props.SetPosition(TimeSpan::from(Duration::from_secs_f64(position)));
-        let set_min_seek_time_result =
-          timeline_props.SetMinSeekTime(TimeSpan::from(Duration::from_secs_f64(0.0)));
-        let set_max_seek_time_result =
-          timeline_props.SetMaxSeekTime(TimeSpan::from(Duration::from_secs_f64(duration)));
+        let set_min_seek_time_result = timeline_props.SetMinSeekTime(TimeSpan::from(
+          Duration::from_secs_f64(bounds.min_seek_time),
+        ));
+        let set_max_seek_time_result = timeline_props.SetMaxSeekTime(TimeSpan::from(
+          Duration::from_secs_f64(bounds.max_seek_time),
+        ));
         match set_start_time_result {
-          Err(error) => return Err(napi::Error::from_reason(error.message())),
+          Err(error) => return Err(XosmsError::from(&error).into()),
           Ok(()) => {}
         };
         match set_end_time_result {
-          Err(error) => return Err(napi::Error::from_reason(error.message())),
+          Err(error) => return Err(XosmsError::from(&error).into()),
           Ok(()) => {}
         };
         match set_position_result {
-          Err(error) => return Err(napi::Error::from_reason(error.message())),
+          Err(error) => return Err(XosmsError::from(&error).into()),
           Ok(()) => {}
         };
         match set_min_seek_time_result {
-          Err(error) => return Err(napi::Error::from_reason(error.message())),
+          Err(error) => return Err(XosmsError::from(&error).into()),
           Ok(()) => {}
         };
         match set_max_seek_time_result {
-          Err(error) => return Err(napi::Error::from_reason(error.message())),
+          Err(error) => return Err(XosmsError::from(&error).into()),
+          Ok(()) => {}
+        };
+
+        let update_timeline_properties_result = smtc.UpdateTimelineProperties(&timeline_props);
+        self.timeline_properties = Some(timeline_props);
+        self.timeline_options = options;
+        match update_timeline_properties_result {
+          Err(error) => return Err(XosmsError::from(&error).into()),
+          Ok(()) => Ok(()),
+        }
+      }
+      Err(error) => Err(XosmsError::from(&error).into()),
+    }
+  }
+
+  /// Reports that a seek occurred and immediately emits a seeked signal with the new position in seconds
+  #[napi]
+  #[allow(dead_code)]
+  pub fn seeked(&mut self, position: f64) -> napi::Result<()> {
+    if position.is_nan() || position < 0.0 {
+      return Err(
+        XosmsError::new(
+          XosmsErrorCode::XOSMS_INVALID_TIMELINE,
+          "Position cannot be less than the min seek time",
+        )
+        .detail("position", position)
+        .detail("minSeekTime", 0.0)
+        .into(),
+      );
+    }
+
+    let smtc_result = self.player.SystemMediaTransportControls();
+    match smtc_result {
+      Ok(smtc) => {
+        // The media service reads the position from the timeline so the last timeline is updated in place
+        let timeline_props = match &self.timeline_properties {
+          Some(timeline_props) => timeline_props.clone(),
+          None => SystemMediaTransportControlsTimelineProperties::new().unwrap(),
+        };
+        let set_position_result =
+          timeline_props.SetPosition(TimeSpan::from(Duration::from_secs_f64(position)));
+        match set_position_result {
+          Err(error) => return Err(XosmsError::from(&error).into()),
           Ok(()) => {}
         };
 
         let update_timeline_properties_result = smtc.UpdateTimelineProperties(&timeline_props);
+        self.timeline_properties = Some(timeline_props);
         match update_timeline_properties_result {
-          Err(error) => return Err(napi::Error::from_reason(error.message())),
+          Err(error) => return Err(XosmsError::from(&error).into()),
           Ok(()) => Ok(()),
         }
       }
-      Err(error) => Err(napi::Error::from_reason(error.message())),
+      Err(error) => Err(XosmsError::from(&error).into()),
     }
   }
 
+  /// Gets the current position in seconds
+  ///
+  /// The position is extrapolated from the last timeline update while playing
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_position(&self) -> napi::Result<f64> {
+    Ok(0.0)
+  }
+
+  /// Gets the interval in seconds between 'tick' events
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_tick_interval(&self) -> napi::Result<Option<f64>> {
+    Ok(None)
+  }
+
+  /// Sets the interval in seconds between 'tick' events
+  ///
+  /// The 'tick' and 'ended' events are only emitted while a tick interval is set
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_tick_interval(&mut self, tick_interval: Option<f64>) -> napi::Result<()> {
+    if let Some(tick_interval) = tick_interval {
+      if !Duration::try_from_secs_f64(tick_interval).is_ok_and(|duration| !duration.is_zero()) {
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+            format!(
+              "{} is not a valid tick interval, it must be a finite number greater than 0",
+              tick_interval
+            ),
+          )
+          .detail("value", tick_interval)
+          .into(),
+        );
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Advances the playback clock by the provided amount of seconds, only available with the mock backend
+  ///
+  /// The playback clock of the mock backend only moves when advanced
+  #[napi]
+  #[allow(dead_code)]
+  pub fn advance_clock(&mut self, seconds: f64) -> napi::Result<()> {
+    if !self.mock {
+      return Err(backend::mock_only("advanceClock").into());
+    }
+    if seconds.is_nan() || seconds < 0.0 {
+      return Err(
+        XosmsError::new(
+          XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+          format!(
+            "{} is not a valid amount of seconds to advance the clock by",
+            seconds
+          ),
+        )
+        .detail("value", seconds)
+        .into(),
+      );
+    }
+
+    Ok(())
+  }
+
   /// Gets the play button enbled state
   #[napi(getter)]
   #[allow(dead_code)]
@@ -523,11 +1359,11 @@ impl MediaPlayer {
       Ok(smtc) => {
         let get_is_play_enabled_result = smtc.IsPlayEnabled();
         match get_is_play_enabled_result {
-          Err(error) => return Err(napi::Error::from_reason(error.message())),
+          Err(error) => return Err(XosmsError::from(&error).into()),
           Ok(is_play_enabled) => Ok(is_play_enabled),
         }
       }
-      Err(error) => Err(napi::Error::from_reason(error.message())),
+      Err(error) => Err(XosmsError::from(&error).into()),
     }
   }
 
@@ -540,11 +1376,11 @@ impl MediaPlayer {
       Ok(smtc) => {
         let set_is_play_enabled_result = smtc.SetIsPlayEnabled(enabled);
         match set_is_play_enabled_result {
-          Err(error) => return Err(napi::Error::from_reason(error.message())),
+          Err(error) => return Err(XosmsError::from(&error).into()),
           Ok(()) => Ok(()),
         }
       }
-      Err(error) => Err(napi::Error::from_reason(error.message())),
+      Err(error) => Err(XosmsError::from(&error).into()),
     }
   }
 
@@ -557,11 +1393,11 @@ impl MediaPlayer {
       Ok(smtc) => {
         let get_is_pause_enabled_result = smtc.IsPauseEnabled();
         match get_is_pause_enabled_result {
-          Err(error) => return Err(napi::Error::from_reason(error.message())),
+          Err(error) => return Err(XosmsError::from(&error).into()),
           Ok(is_pause_enabled) => Ok(is_pause_enabled),
         }
       }
-      Err(error) => Err(napi::Error::from_reason(error.message())),
+      Err(error) => Err(XosmsError::from(&error).into()),
     }
   }
 
@@ -574,11 +1410,11 @@ impl MediaPlayer {
       Ok(smtc) => {
         let set_is_pause_enabled_result = smtc.SetIsPauseEnabled(enabled);
         match set_is_pause_enabled_result {
-          Err(error) => return Err(napi::Error::from_reason(error.message())),
+          Err(error) => return Err(XosmsError::from(&error).into()),
           Ok(()) => Ok(()),
         }
       }
-      Err(error) => Err(napi::Error::from_reason(error.message())),
+      Err(error) => Err(XosmsError::from(&error).into()),
     }
   }
 
@@ -591,11 +1427,11 @@ impl MediaPlayer {
       Ok(smtc) => {
         let get_is_stop_enabled_result = smtc.IsStopEnabled();
         match get_is_stop_enabled_result {
-          Err(error) => return Err(napi::Error::from_reason(error.message())),
+          Err(error) => return Err(XosmsError::from(&error).into()),
           Ok(is_stop_enabled) => Ok(is_stop_enabled),
         }
       }
-      Err(error) => Err(napi::Error::from_reason(error.message())),
+      Err(error) => Err(XosmsError::from(&error).into()),
     }
   }
 
@@ -608,11 +1444,11 @@ impl MediaPlayer {
       Ok(smtc) => {
         let set_is_stop_enabled_result = smtc.SetIsStopEnabled(enabled);
         match set_is_stop_enabled_result {
-          Err(error) => return Err(napi::Error::from_reason(error.message())),
+          Err(error) => return Err(XosmsError::from(&error).into()),
           Ok(()) => Ok(()),
         }
       }
-      Err(error) => Err(napi::Error::from_reason(error.message())),
+      Err(error) => Err(XosmsError::from(&error).into()),
     }
   }
 
@@ -625,11 +1461,11 @@ impl MediaPlayer {
       Ok(smtc) => {
         let get_is_previous_enabled_result = smtc.IsPreviousEnabled();
         match get_is_previous_enabled_result {
-          Err(error) => return Err(napi::Error::from_reason(error.message())),
+          Err(error) => return Err(XosmsError::from(&error).into()),
           Ok(is_previous_enabled) => Ok(is_previous_enabled),
         }
       }
-      Err(error) => Err(napi::Error::from_reason(error.message())),
+      Err(error) => Err(XosmsError::from(&error).into()),
     }
   }
 
@@ -642,11 +1478,11 @@ impl MediaPlayer {
       Ok(smtc) => {
         let set_is_previous_enabled_result = smtc.SetIsPreviousEnabled(enabled);
         match set_is_previous_enabled_result {
-          Err(error) => return Err(napi::Error::from_reason(error.message())),
+          Err(error) => return Err(XosmsError::from(&error).into()),
           Ok(()) => Ok(()),
         }
       }
-      Err(error) => Err(napi::Error::from_reason(error.message())),
+      Err(error) => Err(XosmsError::from(&error).into()),
     }
   }
 
@@ -659,11 +1495,11 @@ impl MediaPlayer {
       Ok(smtc) => {
         let get_is_next_enabled_result = smtc.IsNextEnabled();
         match get_is_next_enabled_result {
-          Err(error) => return Err(napi::Error::from_reason(error.message())),
+          Err(error) => return Err(XosmsError::from(&error).into()),
           Ok(is_next_enabled) => Ok(is_next_enabled),
         }
       }
-      Err(error) => Err(napi::Error::from_reason(error.message())),
+      Err(error) => Err(XosmsError::from(&error).into()),
     }
   }
 
@@ -676,11 +1512,11 @@ impl MediaPlayer {
       Ok(smtc) => {
         let set_is_next_enabled_result = smtc.SetIsNextEnabled(enabled);
         match set_is_next_enabled_result {
-          Err(error) => return Err(napi::Error::from_reason(error.message())),
+          Err(error) => return Err(XosmsError::from(&error).into()),
           Ok(()) => Ok(()),
         }
       }
-      Err(error) => Err(napi::Error::from_reason(error.message())),
+      Err(error) => Err(XosmsError::from(&error).into()),
     }
   }
 
@@ -698,20 +1534,140 @@ impl MediaPlayer {
     Ok(())
   }
 
-  /// Gets the playback rate
+  /// Gets the identity of the media player
   #[napi(getter)]
   #[allow(dead_code)]
-  pub fn get_playback_rate(&self) -> napi::Result<f64> {
-    let smtc_result = self.player.SystemMediaTransportControls();
-    match smtc_result {
-      Ok(smtc) => {
+  pub fn get_identity(&self) -> napi::Result<String> {
+    Ok("".to_string())
+  }
+
+  /// Sets the identity of the media player
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_identity(&mut self, _identity: String) -> napi::Result<()> {
+    Ok(())
+  }
+
+  /// Gets the desktop entry name of the media player without the .desktop extension
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_desktop_entry(&self) -> napi::Result<String> {
+    Ok("".to_string())
+  }
+
+  /// Sets the desktop entry name of the media player without the .desktop extension
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_desktop_entry(&mut self, _desktop_entry: String) -> napi::Result<()> {
+    Ok(())
+  }
+
+  /// Gets the fullscreen state
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_fullscreen(&self) -> napi::Result<bool> {
+    Ok(false)
+  }
+
+  /// Sets the fullscreen state
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_fullscreen(&mut self, _enabled: bool) -> napi::Result<()> {
+    Ok(())
+  }
+
+  /// Gets whether the fullscreen state can be set
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_can_set_fullscreen(&self) -> napi::Result<bool> {
+    Ok(false)
+  }
+
+  /// Sets whether the fullscreen state can be set
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_can_set_fullscreen(&mut self, _enabled: bool) -> napi::Result<()> {
+    Ok(())
+  }
+
+  /// Gets the raise enabled state
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_raise_enabled(&self) -> napi::Result<bool> {
+    Ok(false)
+  }
+
+  /// Sets the raise enabled state
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_raise_enabled(&mut self, _enabled: bool) -> napi::Result<()> {
+    Ok(())
+  }
+
+  /// Gets the quit enabled state
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_quit_enabled(&self) -> napi::Result<bool> {
+    Ok(false)
+  }
+
+  /// Sets the quit enabled state
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_quit_enabled(&mut self, _enabled: bool) -> napi::Result<()> {
+    Ok(())
+  }
+
+  /// Gets the uri schemes that can be opened
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_supported_uri_schemes(&self) -> napi::Result<Vec<String>> {
+    Ok(vec![])
+  }
+
+  /// Sets the uri schemes that can be opened
+  ///
+  /// Only uris with one of these schemes will be emitted through the 'openuri' event
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_supported_uri_schemes(
+    &mut self,
+    _supported_uri_schemes: Vec<String>,
+  ) -> napi::Result<()> {
+    Ok(())
+  }
+
+  /// Gets the mime types that can be opened
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_supported_mime_types(&self) -> napi::Result<Vec<String>> {
+    Ok(vec![])
+  }
+
+  /// Sets the mime types that can be opened
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_supported_mime_types(
+    &mut self,
+    _supported_mime_types: Vec<String>,
+  ) -> napi::Result<()> {
+    Ok(())
+  }
+
+  /// Gets the playback rate
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_playback_rate(&self) -> napi::Result<f64> {
+    let smtc_result = self.player.SystemMediaTransportControls();
+    match smtc_result {
+      Ok(smtc) => {
         let get_playback_rate_result = smtc.PlaybackRate();
         match get_playback_rate_result {
-          Err(error) => return Err(napi::Error::from_reason(error.message())),
+          Err(error) => return Err(XosmsError::from(&error).into()),
           Ok(playback_rate) => Ok(playback_rate),
         }
       }
-      Err(error) => Err(napi::Error::from_reason(error.message())),
+      Err(error) => Err(XosmsError::from(&error).into()),
     }
   }
 
@@ -724,12 +1680,73 @@ impl MediaPlayer {
       Ok(smtc) => {
         let set_playback_rate_result = smtc.SetPlaybackRate(playback_rate);
         match set_playback_rate_result {
-          Err(error) => return Err(napi::Error::from_reason(error.message())),
+          Err(error) => return Err(XosmsError::from(&error).into()),
           Ok(()) => Ok(()),
         }
       }
-      Err(error) => Err(napi::Error::from_reason(error.message())),
+      Err(error) => Err(XosmsError::from(&error).into()),
+    }
+  }
+
+  /// Gets the minimum playback rate
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_minimum_playback_rate(&self) -> napi::Result<f64> {
+    Ok(1.0)
+  }
+
+  /// Sets the minimum playback rate
+  ///
+  /// The minimum playback rate must be greater than 0.0 and at most 1.0
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_minimum_playback_rate(&mut self, minimum_playback_rate: f64) -> napi::Result<()> {
+    if minimum_playback_rate.is_nan() || minimum_playback_rate <= 0.0 || minimum_playback_rate > 1.0
+    {
+      return Err(
+        XosmsError::new(
+          XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+          format!(
+            "{} is not a valid minimum playback rate, it must be greater than 0.0 and at most 1.0",
+            minimum_playback_rate
+          ),
+        )
+        .detail("value", minimum_playback_rate)
+        .into(),
+      );
+    }
+
+    Ok(())
+  }
+
+  /// Gets the maximum playback rate
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_maximum_playback_rate(&self) -> napi::Result<f64> {
+    Ok(1.0)
+  }
+
+  /// Sets the maximum playback rate
+  ///
+  /// The maximum playback rate must be at least 1.0
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_maximum_playback_rate(&mut self, maximum_playback_rate: f64) -> napi::Result<()> {
+    if maximum_playback_rate.is_nan() || maximum_playback_rate < 1.0 {
+      return Err(
+        XosmsError::new(
+          XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+          format!(
+            "{} is not a valid maximum playback rate, it must be at least 1.0",
+            maximum_playback_rate
+          ),
+        )
+        .detail("value", maximum_playback_rate)
+        .into(),
+      );
     }
+
+    Ok(())
   }
 
   /// Gets the playback status
@@ -741,7 +1758,7 @@ impl MediaPlayer {
       Ok(smtc) => {
         let get_playback_status_result = smtc.PlaybackStatus();
         match get_playback_status_result {
-          Err(error) => return Err(napi::Error::from_reason(error.message())),
+          Err(error) => return Err(XosmsError::from(&error).into()),
           Ok(playback_status) => Ok(match playback_status {
             MediaPlaybackStatus::Playing => MediaPlayerPlaybackStatus::Playing,
             MediaPlaybackStatus::Paused => MediaPlayerPlaybackStatus::Paused,
@@ -750,7 +1767,7 @@ impl MediaPlayer {
           }),
         }
       }
-      Err(error) => Err(napi::Error::from_reason(error.message())),
+      Err(error) => Err(XosmsError::from(&error).into()),
     }
   }
 
@@ -769,19 +1786,145 @@ impl MediaPlayer {
           MediaPlayerPlaybackStatus::Paused => MediaPlaybackStatus::Paused,
           MediaPlayerPlaybackStatus::Stopped => MediaPlaybackStatus::Stopped,
           _ => {
-            return Err(napi::Error::from_reason(format!(
-              "{:?} is not a valid MediaPlayerPlaybackStatus to set",
-              playback_status
-            )))
+            return Err(
+              XosmsError::new(
+                XosmsErrorCode::XOSMS_INVALID_ENUM,
+                format!(
+                  "{:?} is not a valid MediaPlayerPlaybackStatus to set",
+                  playback_status
+                ),
+              )
+              .detail("value", playback_status as i32)
+              .detail("enum", "MediaPlayerPlaybackStatus")
+              .into(),
+            )
           }
         });
         match set_playback_status_result {
-          Err(error) => return Err(napi::Error::from_reason(error.message())),
+          Err(error) => return Err(XosmsError::from(&error).into()),
+          Ok(()) => Ok(()),
+        }
+      }
+      Err(error) => Err(XosmsError::from(&error).into()),
+    }
+  }
+
+  /// Gets the repeat mode
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_repeat_mode(&self) -> napi::Result<MediaPlayerRepeatMode> {
+    let smtc_result = self.player.SystemMediaTransportControls();
+    match smtc_result {
+      Ok(smtc) => {
+        let get_auto_repeat_mode_result = smtc.AutoRepeatMode();
+        match get_auto_repeat_mode_result {
+          Err(error) => return Err(XosmsError::from(&error).into()),
+          Ok(auto_repeat_mode) => Ok(match auto_repeat_mode {
+            MediaPlaybackAutoRepeatMode::None => MediaPlayerRepeatMode::None,
+            MediaPlaybackAutoRepeatMode::Track => MediaPlayerRepeatMode::Track,
+            MediaPlaybackAutoRepeatMode::List => MediaPlayerRepeatMode::Playlist,
+            _ => MediaPlayerRepeatMode::Unknown,
+          }),
+        }
+      }
+      Err(error) => Err(XosmsError::from(&error).into()),
+    }
+  }
+
+  /// Sets the repeat mode
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_repeat_mode(&mut self, repeat_mode: MediaPlayerRepeatMode) -> napi::Result<()> {
+    let smtc_result = self.player.SystemMediaTransportControls();
+    match smtc_result {
+      Ok(smtc) => {
+        let set_auto_repeat_mode_result = smtc.SetAutoRepeatMode(match repeat_mode {
+          MediaPlayerRepeatMode::None => MediaPlaybackAutoRepeatMode::None,
+          MediaPlayerRepeatMode::Track => MediaPlaybackAutoRepeatMode::Track,
+          MediaPlayerRepeatMode::Playlist => MediaPlaybackAutoRepeatMode::List,
+          _ => {
+            return Err(
+              XosmsError::new(
+                XosmsErrorCode::XOSMS_INVALID_ENUM,
+                format!(
+                  "{:?} is not a valid MediaPlayerRepeatMode to set",
+                  repeat_mode
+                ),
+              )
+              .detail("value", repeat_mode as i32)
+              .detail("enum", "MediaPlayerRepeatMode")
+              .into(),
+            )
+          }
+        });
+        match set_auto_repeat_mode_result {
+          Err(error) => return Err(XosmsError::from(&error).into()),
+          Ok(()) => Ok(()),
+        }
+      }
+      Err(error) => Err(XosmsError::from(&error).into()),
+    }
+  }
+
+  /// Gets the shuffle state
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_shuffle(&self) -> napi::Result<bool> {
+    let smtc_result = self.player.SystemMediaTransportControls();
+    match smtc_result {
+      Ok(smtc) => {
+        let get_shuffle_enabled_result = smtc.ShuffleEnabled();
+        match get_shuffle_enabled_result {
+          Err(error) => return Err(XosmsError::from(&error).into()),
+          Ok(shuffle_enabled) => Ok(shuffle_enabled),
+        }
+      }
+      Err(error) => Err(XosmsError::from(&error).into()),
+    }
+  }
+
+  /// Sets the shuffle state
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_shuffle(&mut self, shuffle: bool) -> napi::Result<()> {
+    let smtc_result = self.player.SystemMediaTransportControls();
+    match smtc_result {
+      Ok(smtc) => {
+        let set_shuffle_enabled_result = smtc.SetShuffleEnabled(shuffle);
+        match set_shuffle_enabled_result {
+          Err(error) => return Err(XosmsError::from(&error).into()),
           Ok(()) => Ok(()),
         }
       }
-      Err(error) => Err(napi::Error::from_reason(error.message())),
+      Err(error) => Err(XosmsError::from(&error).into()),
+    }
+  }
+
+  /// Gets the volume
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_volume(&self) -> napi::Result<f64> {
+    Ok(1.0)
+  }
+
+  /// Sets the volume
+  ///
+  /// The volume is clamped between 0.0 and 1.0
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_volume(&mut self, volume: f64) -> napi::Result<()> {
+    if volume.is_nan() {
+      return Err(
+        XosmsError::new(
+          XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+          "Volume must be a number",
+        )
+        .detail("value", volume)
+        .into(),
+      );
     }
+
+    Ok(())
   }
 
   /// Gets the media type
@@ -795,17 +1938,17 @@ impl MediaPlayer {
         if let Ok(du) = du_result {
           let get_type_result = du.Type();
           match get_type_result {
-            Err(error) => return Err(napi::Error::from_reason(error.message())),
+            Err(error) => return Err(XosmsError::from(&error).into()),
             Ok(media_type) => Ok(match media_type {
               MediaPlaybackType::Music => MediaPlayerMediaType::Music,
               _ => MediaPlayerMediaType::Unknown,
             }),
           }
         } else {
-          Err(napi::Error::from_reason(du_result.unwrap_err().message()))
+          Err(XosmsError::from(&du_result.unwrap_err()).into())
         }
       }
-      Err(error) => Err(napi::Error::from_reason(error.message())),
+      Err(error) => Err(XosmsError::from(&error).into()),
     }
   }
 
@@ -821,21 +1964,29 @@ impl MediaPlayer {
           let set_type_result = du.SetType(match media_type {
             MediaPlayerMediaType::Music => MediaPlaybackType::Music,
             _ => {
-              return Err(napi::Error::from_reason(format!(
-                "{:?} is not a valid MediaPlayerMediaType to set",
-                media_type
-              )))
+              return Err(
+                XosmsError::new(
+                  XosmsErrorCode::XOSMS_INVALID_ENUM,
+                  format!(
+                    "{:?} is not a valid MediaPlayerMediaType to set",
+                    media_type
+                  ),
+                )
+                .detail("value", media_type as i32)
+                .detail("enum", "MediaPlayerMediaType")
+                .into(),
+              )
             }
           });
           match set_type_result {
-            Err(error) => return Err(napi::Error::from_reason(error.message())),
+            Err(error) => return Err(XosmsError::from(&error).into()),
             Ok(()) => Ok(()),
           }
         } else {
-          Err(napi::Error::from_reason(du_result.unwrap_err().message()))
+          Err(XosmsError::from(&du_result.unwrap_err()).into())
         }
       }
-      Err(error) => Err(napi::Error::from_reason(error.message())),
+      Err(error) => Err(XosmsError::from(&error).into()),
     }
   }
 
@@ -852,17 +2003,17 @@ impl MediaPlayer {
           if let Ok(mp) = mp_result {
             let get_title_result = mp.Title();
             match get_title_result {
-              Err(error) => return Err(napi::Error::from_reason(error.message())),
+              Err(error) => return Err(XosmsError::from(&error).into()),
               Ok(title) => Ok(title.to_string()),
             }
           } else {
-            Err(napi::Error::from_reason(mp_result.unwrap_err().message()))
+            Err(XosmsError::from(&mp_result.unwrap_err()).into())
           }
         } else {
-          Err(napi::Error::from_reason(du_result.unwrap_err().message()))
+          Err(XosmsError::from(&du_result.unwrap_err()).into())
         }
       }
-      Err(error) => Err(napi::Error::from_reason(error.message())),
+      Err(error) => Err(XosmsError::from(&error).into()),
     }
   }
 
@@ -879,17 +2030,17 @@ impl MediaPlayer {
           if let Ok(mp) = mp_result {
             let set_title_result = mp.SetTitle(&HSTRING::from(title));
             match set_title_result {
-              Err(error) => return Err(napi::Error::from_reason(error.message())),
+              Err(error) => return Err(XosmsError::from(&error).into()),
               Ok(()) => Ok(()),
             }
           } else {
-            Err(napi::Error::from_reason(mp_result.unwrap_err().message()))
+            Err(XosmsError::from(&mp_result.unwrap_err()).into())
           }
         } else {
-          Err(napi::Error::from_reason(du_result.unwrap_err().message()))
+          Err(XosmsError::from(&du_result.unwrap_err()).into())
         }
       }
-      Err(error) => Err(napi::Error::from_reason(error.message())),
+      Err(error) => Err(XosmsError::from(&error).into()),
     }
   }
 
@@ -906,17 +2057,17 @@ impl MediaPlayer {
           if let Ok(mp) = mp_result {
             let get_artist_result = mp.Artist();
             match get_artist_result {
-              Err(error) => return Err(napi::Error::from_reason(error.message())),
+              Err(error) => return Err(XosmsError::from(&error).into()),
               Ok(artist) => Ok(artist.to_string()),
             }
           } else {
-            Err(napi::Error::from_reason(mp_result.unwrap_err().message()))
+            Err(XosmsError::from(&mp_result.unwrap_err()).into())
           }
         } else {
-          Err(napi::Error::from_reason(du_result.unwrap_err().message()))
+          Err(XosmsError::from(&du_result.unwrap_err()).into())
         }
       }
-      Err(error) => Err(napi::Error::from_reason(error.message())),
+      Err(error) => Err(XosmsError::from(&error).into()),
     }
   }
 
@@ -933,24 +2084,24 @@ impl MediaPlayer {
           if let Ok(mp) = mp_result {
             let set_artist_result = mp.SetArtist(&HSTRING::from(artist));
             match set_artist_result {
-              Err(error) => return Err(napi::Error::from_reason(error.message())),
+              Err(error) => return Err(XosmsError::from(&error).into()),
               Ok(()) => Ok(()),
             }
           } else {
-            Err(napi::Error::from_reason(mp_result.unwrap_err().message()))
+            Err(XosmsError::from(&mp_result.unwrap_err()).into())
           }
         } else {
-          Err(napi::Error::from_reason(du_result.unwrap_err().message()))
+          Err(XosmsError::from(&du_result.unwrap_err()).into())
         }
       }
-      Err(error) => Err(napi::Error::from_reason(error.message())),
+      Err(error) => Err(XosmsError::from(&error).into()),
     }
   }
 
-  /// Gets the media album title
+  /// Gets the media artists
   #[napi(getter)]
   #[allow(dead_code)]
-  pub fn get_album_title(&self) -> napi::Result<String> {
+  pub fn get_artists(&self) -> napi::Result<Vec<String>> {
     let smtc_result = self.player.SystemMediaTransportControls();
     match smtc_result {
       Ok(smtc) => {
@@ -958,26 +2109,33 @@ impl MediaPlayer {
         if let Ok(du) = du_result {
           let mp_result = du.MusicProperties();
           if let Ok(mp) = mp_result {
-            let get_album_title_result = mp.AlbumTitle();
-            match get_album_title_result {
-              Err(error) => return Err(napi::Error::from_reason(error.message())),
-              Ok(album_title) => Ok(album_title.to_string()),
+            let get_artists_result = mp.Artist();
+            match get_artists_result {
+              Err(error) => return Err(XosmsError::from(&error).into()),
+              Ok(artists) => Ok(
+                artists
+                  .to_string()
+                  .split("; ")
+                  .filter(|value| !value.is_empty())
+                  .map(|value| value.to_string())
+                  .collect(),
+              ),
             }
           } else {
-            Err(napi::Error::from_reason(mp_result.unwrap_err().message()))
+            Err(XosmsError::from(&mp_result.unwrap_err()).into())
           }
         } else {
-          Err(napi::Error::from_reason(du_result.unwrap_err().message()))
+          Err(XosmsError::from(&du_result.unwrap_err()).into())
         }
       }
-      Err(error) => Err(napi::Error::from_reason(error.message())),
+      Err(error) => Err(XosmsError::from(&error).into()),
     }
   }
 
-  /// Sets the media artist
+  /// Sets the media artists
   #[napi(setter)]
   #[allow(dead_code)]
-  pub fn set_album_title(&mut self, album_title: String) -> napi::Result<()> {
+  pub fn set_artists(&mut self, artists: Vec<String>) -> napi::Result<()> {
     let smtc_result = self.player.SystemMediaTransportControls();
     match smtc_result {
       Ok(smtc) => {
@@ -985,63 +2143,977 @@ impl MediaPlayer {
         if let Ok(du) = du_result {
           let mp_result = du.MusicProperties();
           if let Ok(mp) = mp_result {
-            let set_album_title_result = mp.SetAlbumTitle(&HSTRING::from(album_title));
-            match set_album_title_result {
-              Err(error) => return Err(napi::Error::from_reason(error.message())),
+            let set_artists_result = mp.SetArtist(&HSTRING::from(artists.join("; ")));
+            match set_artists_result {
+              Err(error) => return Err(XosmsError::from(&error).into()),
               Ok(()) => Ok(()),
             }
           } else {
-            Err(napi::Error::from_reason(mp_result.unwrap_err().message()))
+            Err(XosmsError::from(&mp_result.unwrap_err()).into())
           }
         } else {
-          Err(napi::Error::from_reason(du_result.unwrap_err().message()))
+          Err(XosmsError::from(&du_result.unwrap_err()).into())
         }
       }
-      Err(error) => Err(napi::Error::from_reason(error.message())),
+      Err(error) => Err(XosmsError::from(&error).into()),
     }
   }
 
-  /// Gets the track id
+  /// Gets the media album artists
   #[napi(getter)]
   #[allow(dead_code)]
-  pub fn get_track_id(&self) -> napi::Result<String> {
-    Ok("".to_string())
+  pub fn get_album_artists(&self) -> napi::Result<Vec<String>> {
+    let smtc_result = self.player.SystemMediaTransportControls();
+    match smtc_result {
+      Ok(smtc) => {
+        let du_result = smtc.DisplayUpdater();
+        if let Ok(du) = du_result {
+          let mp_result = du.MusicProperties();
+          if let Ok(mp) = mp_result {
+            let get_album_artists_result = mp.AlbumArtist();
+            match get_album_artists_result {
+              Err(error) => return Err(XosmsError::from(&error).into()),
+              Ok(album_artists) => Ok(
+                album_artists
+                  .to_string()
+                  .split("; ")
+                  .filter(|value| !value.is_empty())
+                  .map(|value| value.to_string())
+                  .collect(),
+              ),
+            }
+          } else {
+            Err(XosmsError::from(&mp_result.unwrap_err()).into())
+          }
+        } else {
+          Err(XosmsError::from(&du_result.unwrap_err()).into())
+        }
+      }
+      Err(error) => Err(XosmsError::from(&error).into()),
+    }
   }
 
-  /// Sets the track id
+  /// Sets the media album artists
   #[napi(setter)]
   #[allow(dead_code)]
-  pub fn set_track_id(&mut self, track_id: String) -> napi::Result<()> {
-    Ok(())
-  }
-}
-
-impl ObjectFinalize for MediaPlayer {
-  fn finalize(self, _env: napi::Env) -> napi::Result<()> {
+  pub fn set_album_artists(&mut self, album_artists: Vec<String>) -> napi::Result<()> {
     let smtc_result = self.player.SystemMediaTransportControls();
-    if let Ok(smtc) = smtc_result {
-      let remove_button_pressed_result =
-        smtc.RemoveButtonPressed(self.smtc_button_pressed_registration);
-      if let Err(error) = remove_button_pressed_result {
-        return Err(napi::Error::from_reason(error.message()));
-      }
-
-      let remove_playback_position_changed_result = smtc
-        .RemovePlaybackPositionChangeRequested(self.smtc_playback_position_changed_registration);
-      if let Err(error) = remove_playback_position_changed_result {
-        return Err(napi::Error::from_reason(error.message()));
+    match smtc_result {
+      Ok(smtc) => {
+        let du_result = smtc.DisplayUpdater();
+        if let Ok(du) = du_result {
+          let mp_result = du.MusicProperties();
+          if let Ok(mp) = mp_result {
+            let set_album_artists_result =
+              mp.SetAlbumArtist(&HSTRING::from(album_artists.join("; ")));
+            match set_album_artists_result {
+              Err(error) => return Err(XosmsError::from(&error).into()),
+              Ok(()) => Ok(()),
+            }
+          } else {
+            Err(XosmsError::from(&mp_result.unwrap_err()).into())
+          }
+        } else {
+          Err(XosmsError::from(&du_result.unwrap_err()).into())
+        }
       }
-    } else {
-      return Err(napi::Error::from_reason(smtc_result.unwrap_err().message()));
+      Err(error) => Err(XosmsError::from(&error).into()),
     }
-    self.button_pressed_listeners.clear();
-    self.playback_position_changed_listeners.clear();
+  }
 
-    let close_result = self.player.Close();
-    if let Err(error) = close_result {
-      return Err(napi::Error::from_reason(error.message()));
+  /// Gets the media album title
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_album_title(&self) -> napi::Result<String> {
+    let smtc_result = self.player.SystemMediaTransportControls();
+    match smtc_result {
+      Ok(smtc) => {
+        let du_result = smtc.DisplayUpdater();
+        if let Ok(du) = du_result {
+          let mp_result = du.MusicProperties();
+          if let Ok(mp) = mp_result {
+            let get_album_title_result = mp.AlbumTitle();
+            match get_album_title_result {
+              Err(error) => return Err(XosmsError::from(&error).into()),
+              Ok(album_title) => Ok(album_title.to_string()),
+            }
+          } else {
+            Err(XosmsError::from(&mp_result.unwrap_err()).into())
+          }
+        } else {
+          Err(XosmsError::from(&du_result.unwrap_err()).into())
+        }
+      }
+      Err(error) => Err(XosmsError::from(&error).into()),
     }
+  }
 
-    Ok(())
+  /// Sets the media artist
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_album_title(&mut self, album_title: String) -> napi::Result<()> {
+    let smtc_result = self.player.SystemMediaTransportControls();
+    match smtc_result {
+      Ok(smtc) => {
+        let du_result = smtc.DisplayUpdater();
+        if let Ok(du) = du_result {
+          let mp_result = du.MusicProperties();
+          if let Ok(mp) = mp_result {
+            let set_album_title_result = mp.SetAlbumTitle(&HSTRING::from(album_title));
+            match set_album_title_result {
+              Err(error) => return Err(XosmsError::from(&error).into()),
+              Ok(()) => Ok(()),
+            }
+          } else {
+            Err(XosmsError::from(&mp_result.unwrap_err()).into())
+          }
+        } else {
+          Err(XosmsError::from(&du_result.unwrap_err()).into())
+        }
+      }
+      Err(error) => Err(XosmsError::from(&error).into()),
+    }
+  }
+
+  /// Gets the media genres
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_genres(&self) -> napi::Result<Vec<String>> {
+    let smtc_result = self.player.SystemMediaTransportControls();
+    match smtc_result {
+      Ok(smtc) => {
+        let du_result = smtc.DisplayUpdater();
+        if let Ok(du) = du_result {
+          let mp_result = du.MusicProperties();
+          if let Ok(mp) = mp_result {
+            let get_genres_result = mp.Genres();
+            match get_genres_result {
+              Err(error) => return Err(XosmsError::from(&error).into()),
+              Ok(genres) => {
+                let mut values = vec![];
+                for index in 0..genres.Size().unwrap_or(0) {
+                  if let Ok(genre) = genres.GetAt(index) {
+                    values.push(genre.to_string());
+                  }
+                }
+                Ok(values)
+              }
+            }
+          } else {
+            Err(XosmsError::from(&mp_result.unwrap_err()).into())
+          }
+        } else {
+          Err(XosmsError::from(&du_result.unwrap_err()).into())
+        }
+      }
+      Err(error) => Err(XosmsError::from(&error).into()),
+    }
+  }
+
+  /// Sets the media genres
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_genres(&mut self, genres: Vec<String>) -> napi::Result<()> {
+    let smtc_result = self.player.SystemMediaTransportControls();
+    match smtc_result {
+      Ok(smtc) => {
+        let du_result = smtc.DisplayUpdater();
+        if let Ok(du) = du_result {
+          let mp_result = du.MusicProperties();
+          if let Ok(mp) = mp_result {
+            let get_genres_result = mp.Genres();
+            match get_genres_result {
+              Err(error) => return Err(XosmsError::from(&error).into()),
+              Ok(values) => {
+                if let Err(error) = values.Clear() {
+                  return Err(XosmsError::from(&error).into());
+                }
+                for genre in genres {
+                  if let Err(error) = values.Append(&HSTRING::from(genre)) {
+                    return Err(XosmsError::from(&error).into());
+                  }
+                }
+                Ok(())
+              }
+            }
+          } else {
+            Err(XosmsError::from(&mp_result.unwrap_err()).into())
+          }
+        } else {
+          Err(XosmsError::from(&du_result.unwrap_err()).into())
+        }
+      }
+      Err(error) => Err(XosmsError::from(&error).into()),
+    }
+  }
+
+  /// Gets the media composers
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_composers(&self) -> napi::Result<Vec<String>> {
+    Ok(vec![])
+  }
+
+  /// Sets the media composers
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_composers(&mut self, _composers: Vec<String>) -> napi::Result<()> {
+    Ok(())
+  }
+
+  /// Gets the media lyricists
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_lyricists(&self) -> napi::Result<Vec<String>> {
+    Ok(vec![])
+  }
+
+  /// Sets the media lyricists
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_lyricists(&mut self, _lyricists: Vec<String>) -> napi::Result<()> {
+    Ok(())
+  }
+
+  /// Gets the media comments
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_comments(&self) -> napi::Result<Vec<String>> {
+    Ok(vec![])
+  }
+
+  /// Sets the media comments
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_comments(&mut self, _comments: Vec<String>) -> napi::Result<()> {
+    Ok(())
+  }
+
+  /// Gets the media track number
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_track_number(&self) -> napi::Result<Option<i32>> {
+    let smtc_result = self.player.SystemMediaTransportControls();
+    match smtc_result {
+      Ok(smtc) => {
+        let du_result = smtc.DisplayUpdater();
+        if let Ok(du) = du_result {
+          let mp_result = du.MusicProperties();
+          if let Ok(mp) = mp_result {
+            let get_track_number_result = mp.TrackNumber();
+            match get_track_number_result {
+              Err(error) => return Err(XosmsError::from(&error).into()),
+              Ok(0) => Ok(None),
+              Ok(track_number) => Ok(Some(track_number as i32)),
+            }
+          } else {
+            Err(XosmsError::from(&mp_result.unwrap_err()).into())
+          }
+        } else {
+          Err(XosmsError::from(&du_result.unwrap_err()).into())
+        }
+      }
+      Err(error) => Err(XosmsError::from(&error).into()),
+    }
+  }
+
+  /// Sets the media track number
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_track_number(&mut self, track_number: Option<i32>) -> napi::Result<()> {
+    if let Some(track_number) = track_number {
+      if track_number < 0 {
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+            format!(
+              "{} is not a valid track number, it must be at least 0",
+              track_number
+            ),
+          )
+          .detail("value", track_number)
+          .into(),
+        );
+      }
+    }
+
+    let smtc_result = self.player.SystemMediaTransportControls();
+    match smtc_result {
+      Ok(smtc) => {
+        let du_result = smtc.DisplayUpdater();
+        if let Ok(du) = du_result {
+          let mp_result = du.MusicProperties();
+          if let Ok(mp) = mp_result {
+            let set_track_number_result = mp.SetTrackNumber(track_number.unwrap_or(0) as u32);
+            match set_track_number_result {
+              Err(error) => return Err(XosmsError::from(&error).into()),
+              Ok(()) => Ok(()),
+            }
+          } else {
+            Err(XosmsError::from(&mp_result.unwrap_err()).into())
+          }
+        } else {
+          Err(XosmsError::from(&du_result.unwrap_err()).into())
+        }
+      }
+      Err(error) => Err(XosmsError::from(&error).into()),
+    }
+  }
+
+  /// Gets the media disc number
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_disc_number(&self) -> napi::Result<Option<i32>> {
+    Ok(None)
+  }
+
+  /// Sets the media disc number
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_disc_number(&mut self, disc_number: Option<i32>) -> napi::Result<()> {
+    if let Some(disc_number) = disc_number {
+      if disc_number < 0 {
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+            format!(
+              "{} is not a valid disc number, it must be at least 0",
+              disc_number
+            ),
+          )
+          .detail("value", disc_number)
+          .into(),
+        );
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Gets the media user rating
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_user_rating(&self) -> napi::Result<Option<f64>> {
+    Ok(None)
+  }
+
+  /// Sets the media user rating
+  ///
+  /// The user rating must be between 0.0 and 1.0
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_user_rating(&mut self, user_rating: Option<f64>) -> napi::Result<()> {
+    if let Some(user_rating) = user_rating {
+      if user_rating.is_nan() || !(0.0..=1.0).contains(&user_rating) {
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+            format!(
+              "{} is not a valid user rating, it must be between 0.0 and 1.0",
+              user_rating
+            ),
+          )
+          .detail("value", user_rating)
+          .into(),
+        );
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Gets the media use count
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_use_count(&self) -> napi::Result<Option<i32>> {
+    Ok(None)
+  }
+
+  /// Sets the media use count
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_use_count(&mut self, use_count: Option<i32>) -> napi::Result<()> {
+    if let Some(use_count) = use_count {
+      if use_count < 0 {
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+            format!(
+              "{} is not a valid use count, it must be at least 0",
+              use_count
+            ),
+          )
+          .detail("value", use_count)
+          .into(),
+        );
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Gets the media url
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_url(&self) -> napi::Result<String> {
+    Ok("".to_string())
+  }
+
+  /// Sets the media url
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_url(&mut self, _url: String) -> napi::Result<()> {
+    Ok(())
+  }
+
+  /// Gets the media content created date
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_content_created(&self) -> napi::Result<Option<f64>> {
+    Ok(None)
+  }
+
+  /// Sets the media content created date
+  ///
+  /// The date is in milliseconds since the unix epoch
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_content_created(&mut self, content_created: Option<f64>) -> napi::Result<()> {
+    if let Some(content_created) = content_created {
+      if !content_created.is_finite() {
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+            format!("{} is not a valid content created date", content_created),
+          )
+          .detail("value", content_created)
+          .into(),
+        );
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Gets the media first used date
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_first_used(&self) -> napi::Result<Option<f64>> {
+    Ok(None)
+  }
+
+  /// Sets the media first used date
+  ///
+  /// The date is in milliseconds since the unix epoch
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_first_used(&mut self, first_used: Option<f64>) -> napi::Result<()> {
+    if let Some(first_used) = first_used {
+      if !first_used.is_finite() {
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+            format!("{} is not a valid first used date", first_used),
+          )
+          .detail("value", first_used)
+          .into(),
+        );
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Gets the media last used date
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_last_used(&self) -> napi::Result<Option<f64>> {
+    Ok(None)
+  }
+
+  /// Sets the media last used date
+  ///
+  /// The date is in milliseconds since the unix epoch
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_last_used(&mut self, last_used: Option<f64>) -> napi::Result<()> {
+    if let Some(last_used) = last_used {
+      if !last_used.is_finite() {
+        return Err(
+          XosmsError::new(
+            XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+            format!("{} is not a valid last used date", last_used),
+          )
+          .detail("value", last_used)
+          .into(),
+        );
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Sets a custom metadata entry which is published alongside the rest of the metadata
+  ///
+  /// The key must be in the form of namespace:name, keys in the mpris namespace and xesam keys set through properties such as xesam:artist are reserved
+  ///
+  /// Integral numbers are published as 64-bit integers, other numbers as doubles and dates as ISO 8601 strings
+  #[napi]
+  #[allow(dead_code)]
+  pub fn set_custom_metadata(
+    &mut self,
+    key: String,
+    #[napi(ts_arg_type = "string | number | boolean | Array<string> | Date")] _value: JsUnknown,
+  ) -> napi::Result<()> {
+    validate_custom_metadata_key(&key)?;
+    Ok(())
+  }
+
+  /// Removes a custom metadata entry
+  #[napi]
+  #[allow(dead_code)]
+  pub fn remove_custom_metadata(&mut self, _key: String) -> napi::Result<()> {
+    Ok(())
+  }
+
+  /// Gets the track id
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_track_id(&self) -> napi::Result<String> {
+    Ok("".to_string())
+  }
+
+  /// Sets the track id
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_track_id(&mut self, track_id: String) -> napi::Result<()> {
+    Ok(())
+  }
+
+  /// Gets the track list
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_track_list(&self) -> napi::Result<Vec<MediaPlayerTrack>> {
+    Ok(vec![])
+  }
+
+  /// Replaces the track list with the provided tracks
+  ///
+  /// The current track id should be the id of the track currently playing, if any, and must be in the provided tracks
+  #[napi]
+  #[allow(dead_code)]
+  pub fn set_track_list(
+    &mut self,
+    _tracks: Vec<MediaPlayerTrack>,
+    _current_track_id: Option<String>,
+  ) -> napi::Result<()> {
+    Ok(())
+  }
+
+  /// Adds a track to the track list
+  ///
+  /// The track is inserted after the track with the provided id or at the start of the track list when no id is provided
+  #[napi]
+  #[allow(dead_code)]
+  pub fn add_track(
+    &mut self,
+    _track: MediaPlayerTrack,
+    _after_track_id: Option<String>,
+  ) -> napi::Result<()> {
+    Ok(())
+  }
+
+  /// Removes a track from the track list
+  #[napi]
+  #[allow(dead_code)]
+  pub fn remove_track(&mut self, _track_id: String) -> napi::Result<()> {
+    Ok(())
+  }
+
+  /// Updates the metadata of a track in the track list
+  #[napi]
+  #[allow(dead_code)]
+  pub fn update_track(&mut self, _track: MediaPlayerTrack) -> napi::Result<()> {
+    Ok(())
+  }
+
+  /// Gets the track list editable state
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_can_edit_tracks(&self) -> napi::Result<bool> {
+    Ok(false)
+  }
+
+  /// Sets the track list editable state
+  ///
+  /// When disabled the media service cannot request tracks to be added or removed
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_can_edit_tracks(&mut self, _enabled: bool) -> napi::Result<()> {
+    Ok(())
+  }
+
+  /// Gets the playlists
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_playlists(&self) -> napi::Result<Vec<MediaPlayerPlaylist>> {
+    Ok(vec![])
+  }
+
+  /// Replaces the playlists with the provided playlists
+  ///
+  /// The order of the provided playlists is used as the user defined ordering
+  #[napi]
+  #[allow(dead_code)]
+  pub fn set_playlists(&mut self, _playlists: Vec<MediaPlayerPlaylist>) -> napi::Result<()> {
+    Ok(())
+  }
+
+  /// Updates a playlist
+  ///
+  /// The media service is notified when the name or icon of the playlist changes
+  #[napi]
+  #[allow(dead_code)]
+  pub fn update_playlist(&mut self, _playlist: MediaPlayerPlaylist) -> napi::Result<()> {
+    Ok(())
+  }
+
+  /// Gets the active playlist id
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_active_playlist_id(&self) -> napi::Result<Option<String>> {
+    Ok(None)
+  }
+
+  /// Sets the active playlist id
+  ///
+  /// The playlist must be in the playlists, an empty value means no playlist is active
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_active_playlist_id(&mut self, _playlist_id: Option<String>) -> napi::Result<()> {
+    Ok(())
+  }
+
+  /// Gets the supported playlist orderings
+  #[napi(getter)]
+  #[allow(dead_code)]
+  pub fn get_playlist_orderings(&self) -> napi::Result<Vec<MediaPlayerPlaylistOrdering>> {
+    Ok(vec![])
+  }
+
+  /// Sets the supported playlist orderings
+  ///
+  /// At least one ordering must be supported
+  #[napi(setter)]
+  #[allow(dead_code)]
+  pub fn set_playlist_orderings(
+    &mut self,
+    playlist_orderings: Vec<MediaPlayerPlaylistOrdering>,
+  ) -> napi::Result<()> {
+    if playlist_orderings.is_empty() {
+      return Err(
+        XosmsError::new(
+          XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+          "At least one MediaPlayerPlaylistOrdering must be set",
+        )
+        .into(),
+      );
+    }
+    if let Some(playlist_ordering) = playlist_orderings
+      .iter()
+      .find(|o| matches!(o, MediaPlayerPlaylistOrdering::Unknown))
+    {
+      return Err(
+        XosmsError::new(
+          XosmsErrorCode::XOSMS_INVALID_ENUM,
+          format!(
+            "{:?} is not a valid MediaPlayerPlaylistOrdering to set",
+            playlist_ordering
+          ),
+        )
+        .detail("value", *playlist_ordering as i32)
+        .detail("enum", "MediaPlayerPlaylistOrdering")
+        .into(),
+      );
+    }
+
+    Ok(())
+  }
+}
+
+impl ObjectFinalize for MediaPlayer {
+  fn finalize(self, _env: napi::Env) -> napi::Result<()> {
+    let smtc_result = self.player.SystemMediaTransportControls();
+    if let Ok(smtc) = smtc_result {
+      let remove_button_pressed_result =
+        smtc.RemoveButtonPressed(self.smtc_button_pressed_registration);
+      if let Err(error) = remove_button_pressed_result {
+        return Err(XosmsError::from(&error).into());
+      }
+
+      let remove_playback_position_changed_result = smtc
+        .RemovePlaybackPositionChangeRequested(self.smtc_playback_position_changed_registration);
+      if let Err(error) = remove_playback_position_changed_result {
+        return Err(XosmsError::from(&error).into());
+      }
+
+      let remove_auto_repeat_mode_changed_result =
+        smtc.RemoveAutoRepeatModeChangeRequested(self.smtc_auto_repeat_mode_changed_registration);
+      if let Err(error) = remove_auto_repeat_mode_changed_result {
+        return Err(XosmsError::from(&error).into());
+      }
+
+      let remove_shuffle_enabled_changed_result =
+        smtc.RemoveShuffleEnabledChangeRequested(self.smtc_shuffle_enabled_changed_registration);
+      if let Err(error) = remove_shuffle_enabled_changed_result {
+        return Err(XosmsError::from(&error).into());
+      }
+
+      let remove_playback_rate_changed_result =
+        smtc.RemovePlaybackRateChangeRequested(self.smtc_playback_rate_changed_registration);
+      if let Err(error) = remove_playback_rate_changed_result {
+        return Err(XosmsError::from(&error).into());
+      }
+    } else {
+      return Err(XosmsError::from(&smtc_result.unwrap_err()).into());
+    }
+    self.button_pressed_listeners.clear();
+    self.playback_position_changed_listeners.clear();
+    self.repeat_mode_changed_listeners.clear();
+    self.shuffle_changed_listeners.clear();
+    self.rate_changed_listeners.clear();
+
+    let close_result = self.player.Close();
+    if let Err(error) = close_result {
+      return Err(XosmsError::from(&error).into());
+    }
+
+    Ok(())
+  }
+}
+
+/// Resolves an activation which already completed, as there is nothing to wait for
+pub struct ActivateTask {
+  activation: Option<MediaPlayerActivation>,
+}
+
+impl Task for ActivateTask {
+  type Output = MediaPlayerActivation;
+  type JsValue = MediaPlayerActivation;
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    self
+      .activation
+      .take()
+      .ok_or_else(|| napi::Error::from_reason("Activation was already resolved"))
+  }
+
+  fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(output)
+  }
+}
+
+/// Resolves a deactivation which already completed, as there is nothing to wait for
+pub struct DeactivateTask {}
+
+impl Task for DeactivateTask {
+  type Output = ();
+  type JsValue = ();
+
+  fn compute(&mut self) -> napi::Result<Self::Output> {
+    Ok(())
+  }
+
+  fn resolve(&mut self, _env: Env, _output: Self::Output) -> napi::Result<Self::JsValue> {
+    Ok(())
+  }
+}
+
+impl From<&windows::core::Error> for XosmsError {
+  fn from(error: &windows::core::Error) -> Self {
+    XosmsError::new(
+      XosmsErrorCode::XOSMS_PLATFORM_ERROR,
+      error.message().to_string(),
+    )
+    .detail("hresult", error.code().0)
+  }
+}
+
+struct TimelineBounds {
+  start_time: f64,
+  min_seek_time: f64,
+  max_seek_time: f64,
+}
+
+fn validate_timeline(
+  duration: f64,
+  position: f64,
+  options: &Option<MediaPlayerTimelineOptions>,
+) -> Result<TimelineBounds, XosmsError> {
+  if duration < 0.0 {
+    return Err(
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_TIMELINE,
+        "Duration cannot be less than 0",
+      )
+      .detail("duration", duration),
+    );
+  }
+  if position < 0.0 {
+    return Err(
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_TIMELINE,
+        "Position cannot be less than 0",
+      )
+      .detail("position", position),
+    );
+  }
+  if position > duration {
+    return Err(
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_TIMELINE,
+        "Position cannot be greather than provided duration",
+      )
+      .detail("position", position)
+      .detail("duration", duration),
+    );
+  }
+
+  let start_time = options
+    .as_ref()
+    .and_then(|options| options.start_time)
+    .unwrap_or(0.0);
+  let min_seek_time = options
+    .as_ref()
+    .and_then(|options| options.min_seek_time)
+    .unwrap_or(start_time);
+  let max_seek_time = options
+    .as_ref()
+    .and_then(|options| options.max_seek_time)
+    .unwrap_or(duration);
+  if start_time.is_nan() || start_time < 0.0 || start_time > position {
+    return Err(
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_TIMELINE,
+        "Start time must be between 0 and the provided position",
+      )
+      .detail("startTime", start_time)
+      .detail("position", position),
+    );
+  }
+  if min_seek_time.is_nan() || min_seek_time < start_time {
+    return Err(
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_TIMELINE,
+        "Min seek time cannot be less than the start time",
+      )
+      .detail("minSeekTime", min_seek_time)
+      .detail("startTime", start_time),
+    );
+  }
+  if max_seek_time.is_nan() || max_seek_time < min_seek_time || max_seek_time > duration {
+    return Err(
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_TIMELINE,
+        "Max seek time must be between the min seek time and the provided duration",
+      )
+      .detail("maxSeekTime", max_seek_time)
+      .detail("minSeekTime", min_seek_time)
+      .detail("duration", duration),
+    );
+  }
+  if let Some(seek_threshold) = options.as_ref().and_then(|options| options.seek_threshold) {
+    if seek_threshold.is_nan() || seek_threshold < 0.0 {
+      return Err(
+        XosmsError::new(
+          XosmsErrorCode::XOSMS_INVALID_TIMELINE,
+          "Seek threshold cannot be less than 0",
+        )
+        .detail("seekThreshold", seek_threshold),
+      );
+    }
+  }
+
+  Ok(TimelineBounds {
+    start_time,
+    min_seek_time,
+    max_seek_time,
+  })
+}
+
+fn validate_simulated_seconds(seconds: f64) -> Result<(), XosmsError> {
+  if !seconds.is_finite() {
+    return Err(
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+        "Simulated positions and offsets must be a finite number of seconds",
+      )
+      .detail("value", seconds),
+    );
+  }
+
+  Ok(())
+}
+
+/// Metadata keys set through the MediaPlayer properties, which custom metadata may not replace
+const STRUCTURED_METADATA_KEYS: [&str; 16] = [
+  "xesam:album",
+  "xesam:albumArtist",
+  "xesam:artist",
+  "xesam:comment",
+  "xesam:composer",
+  "xesam:contentCreated",
+  "xesam:discNumber",
+  "xesam:firstUsed",
+  "xesam:genre",
+  "xesam:lastUsed",
+  "xesam:lyricist",
+  "xesam:title",
+  "xesam:trackNumber",
+  "xesam:url",
+  "xesam:useCount",
+  "xesam:userRating",
+];
+
+fn validate_custom_metadata_key(key: &str) -> Result<(), XosmsError> {
+  let is_valid_part = |part: &str| {
+    !part.is_empty()
+      && part
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '.')
+  };
+
+  match key.split_once(':') {
+    Some(("mpris", _)) => Err(
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+        format!(
+          "{} is not a valid custom metadata key, the mpris namespace is reserved",
+          key
+        ),
+      )
+      .detail("value", key),
+    ),
+    Some(_) if STRUCTURED_METADATA_KEYS.contains(&key) => Err(
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+        format!(
+          "{} is not a valid custom metadata key, it is set through its MediaPlayer property",
+          key
+        ),
+      )
+      .detail("value", key),
+    ),
+    Some((namespace, name)) if is_valid_part(namespace) && is_valid_part(name) => Ok(()),
+    _ => Err(
+      XosmsError::new(
+        XosmsErrorCode::XOSMS_INVALID_ARGUMENT,
+        format!(
+          "{} is not a valid custom metadata key, it must be in the form of namespace:name",
+          key
+        ),
+      )
+      .detail("value", key),
+    ),
   }
 }
@@ -0,0 +1,275 @@
+#![cfg(target_os = "linux")]
+
+mod support;
+
+use dbus::{
+  arg::{prop_cast, PropMap},
+  blocking::stdintf::org_freedesktop_dbus::PropertiesPropertiesChanged,
+  message::SignalArgs,
+  Path,
+};
+
+const PLAYER: &str = "org.mpris.MediaPlayer2.Player";
+
+fn track_path(track_id: &str) -> Path<'static> {
+  Path::new(format!("/xosms/trackid/{}", track_id)).unwrap()
+}
+
+#[test]
+fn exposes_properties() {
+  let (_bus, mut player, client) = support::start("Xosms Test");
+  player.eval("player.playbackStatus = xosms.MediaPlayerPlaybackStatus.Playing");
+  player.eval("player.title = 'Title'");
+  player.eval("player.artists = ['First', 'Second']");
+  player.eval("player.trackId = 'track1'");
+  player.eval("player.volume = 0.5");
+
+  assert!(client.has_owner());
+  assert_eq!(
+    client.get::<String>("org.mpris.MediaPlayer2", "Identity"),
+    "Xosms Test"
+  );
+  // Listed even before it is set, as GetAll fails on properties which can not be read
+  assert_eq!(
+    client.get::<String>("org.mpris.MediaPlayer2", "DesktopEntry"),
+    ""
+  );
+  assert_eq!(client.get::<String>(PLAYER, "PlaybackStatus"), "Playing");
+  assert_eq!(client.get::<f64>(PLAYER, "Volume"), 0.5);
+
+  let metadata: PropMap = client.get(PLAYER, "Metadata");
+  assert_eq!(
+    prop_cast::<String>(&metadata, "xesam:title").map(String::as_str),
+    Some("Title")
+  );
+  assert_eq!(
+    prop_cast::<Vec<String>>(&metadata, "xesam:artist"),
+    Some(&vec!["First".to_string(), "Second".to_string()])
+  );
+  assert_eq!(
+    prop_cast::<Path>(&metadata, "mpris:trackid"),
+    Some(&track_path("track1"))
+  );
+}
+
+#[test]
+fn emits_properties_changed() {
+  let (_bus, mut player, client) = support::start("Xosms Test");
+  player.eval("player.title = 'Title'");
+  player.eval("player.playbackStatus = xosms.MediaPlayerPlaybackStatus.Paused");
+  player.eval("player.update()");
+
+  let signal = PropertiesPropertiesChanged::from_message(&client.next_signal())
+    .expect("Expected a PropertiesChanged signal");
+  assert_eq!(signal.interface_name, PLAYER);
+  assert_eq!(
+    prop_cast::<String>(&signal.changed_properties, "PlaybackStatus").map(String::as_str),
+    Some("Paused")
+  );
+  let metadata = prop_cast::<PropMap>(&signal.changed_properties, "Metadata")
+    .expect("Expected the metadata to change");
+  assert_eq!(
+    prop_cast::<String>(metadata, "xesam:title").map(String::as_str),
+    Some("Title")
+  );
+
+  // Values equal to the published ones are not emitted again
+  player.eval("player.title = 'Title'");
+  player.eval("player.shuffle = true");
+  player.eval("player.update()");
+  let signal = PropertiesPropertiesChanged::from_message(&client.next_signal())
+    .expect("Expected a PropertiesChanged signal");
+  let mut changed: Vec<&String> = signal.changed_properties.keys().collect();
+  changed.sort();
+  assert_eq!(changed, vec!["Shuffle"]);
+}
+
+#[test]
+fn media_players_emit_signals_from_their_own_connection() {
+  let (_bus, mut player, client) = support::start("Xosms Test");
+  player.eval("globalThis.second = new xosms.MediaPlayer('xosmstestsecond', 'Second')");
+  player.eval("second.activate()");
+  let first_owner = client.name_owner(support::BUS_NAME);
+  let second_owner = client.name_owner("org.mpris.MediaPlayer2.xosmstestsecond");
+  assert_ne!(first_owner, second_owner);
+
+  // A media player which was never activated has nobody to emit to
+  player.eval("globalThis.inactive = new xosms.MediaPlayer('xosmstestinactive', 'Inactive')");
+  player.eval("inactive.title = 'Inactive'");
+  player.eval("inactive.update()");
+  player.eval("player.title = 'First'");
+  player.eval("player.update()");
+  player.eval("second.title = 'Second'");
+  player.eval("second.update()");
+
+  for (owner, title) in [(first_owner, "First"), (second_owner, "Second")] {
+    let signal = client.next_signal();
+    assert_eq!(signal.sender().as_deref(), Some(owner.as_str()));
+    let signal = PropertiesPropertiesChanged::from_message(&signal)
+      .expect("Expected a PropertiesChanged signal");
+    let metadata = prop_cast::<PropMap>(&signal.changed_properties, "Metadata")
+      .expect("Expected the metadata to change");
+    assert_eq!(
+      prop_cast::<String>(metadata, "xesam:title").map(String::as_str),
+      Some(title)
+    );
+  }
+}
+
+#[test]
+fn rolls_back_activations_which_timed_out() {
+  let (_bus, mut player, client) = support::start("Xosms Test");
+  player.eval(
+    "globalThis.activateLate = (attempt) => { globalThis.late = new xosms.MediaPlayer(`xosmstestlate${attempt}`, 'Late'); try { late.activate({ timeout: 1e-9 }); late.deactivate() } catch (error) { return error.code } }",
+  );
+  // A busy machine may get the response before waiting for it, so new media players are tried until one times out
+  let attempt = (0..10)
+    .find(|attempt| player.eval(&format!("activateLate({})", attempt)) == "\"XOSMS_TIMEOUT\"")
+    .expect("Expected an activation to time out");
+
+  // Registrations are processed in order, so the late one has been rolled back once the next is done
+  player.eval("globalThis.next = new xosms.MediaPlayer('xosmstestnext', 'Next')");
+  player.eval("next.activate()");
+  assert!(client.name_has_owner("org.mpris.MediaPlayer2.xosmstestnext"));
+  assert!(!client.name_has_owner(&format!("org.mpris.MediaPlayer2.xosmstestlate{}", attempt)));
+  assert_eq!(
+    player.eval("late.connectionState === xosms.MediaPlayerConnectionState.Disconnected"),
+    "true"
+  );
+}
+
+#[test]
+fn emits_seeked() {
+  let (_bus, mut player, client) = support::start("Xosms Test");
+  player.eval("player.setTimeline(60, 0)");
+  player.eval("player.seeked(30)");
+
+  let signal = client.next_signal();
+  assert_eq!(signal.member().as_deref(), Some("Seeked"));
+  assert_eq!(signal.read1::<i64>().unwrap(), 30_000_000);
+  assert_eq!(client.get::<i64>(PLAYER, "Position"), 30_000_000);
+}
+
+#[test]
+fn set_state_uses_the_configured_seek_threshold() {
+  let (_bus, mut player, client) = support::start("Xosms Test");
+  player.eval("player.setTimeline(60, 0, { seekThreshold: 10 })");
+  player.eval("player.setState({ position: 5 })");
+  player.eval("player.setState({ position: 30 })");
+
+  // The first jump is within the threshold so the first signal is from the second one
+  let signal = client.next_signal();
+  assert_eq!(signal.member().as_deref(), Some("Seeked"));
+  assert_eq!(signal.read1::<i64>().unwrap(), 30_000_000);
+}
+
+#[test]
+fn set_timeline_keeps_the_previous_options() {
+  let (_bus, mut player, client) = support::start("Xosms Test");
+  player.eval("player.setTimeline(60, 0, { seekThreshold: 10 })");
+  player.eval("player.setTimeline(60, 5)");
+  player.eval("player.setTimeline(60, 30)");
+
+  // The first jump is within the kept threshold so the first signal is from the second one
+  let signal = client.next_signal();
+  assert_eq!(signal.member().as_deref(), Some("Seeked"));
+  assert_eq!(signal.read1::<i64>().unwrap(), 30_000_000);
+}
+
+#[test]
+fn set_position_with_stale_track_id_is_ignored() {
+  let (_bus, mut player, client) = support::start("Xosms Test");
+  player.eval("player.trackId = 'current'");
+  player.eval("player.seekEnabled = true");
+  player.eval("player.setTimeline(60, 0)");
+
+  client
+    .call::<_, ()>(
+      PLAYER,
+      "SetPosition",
+      (track_path("previous"), 10_000_000i64),
+    )
+    .unwrap();
+  client
+    .call::<_, ()>(
+      PLAYER,
+      "SetPosition",
+      (track_path("current"), 20_000_000i64),
+    )
+    .unwrap();
+
+  // Events are emitted in order so the first one would be from the stale track id
+  assert_eq!(
+    player.next_event(),
+    ("positionchanged".to_string(), "20".to_string())
+  );
+}
+
+#[test]
+fn set_position_without_track_id_accepts_no_track() {
+  let (_bus, mut player, client) = support::start("Xosms Test");
+  player.eval("player.seekEnabled = true");
+  player.eval("player.setTimeline(60, 0)");
+
+  // Clients send back the mpris:trackid they read from the metadata
+  let metadata: PropMap = client.get(PLAYER, "Metadata");
+  let no_track = prop_cast::<Path>(&metadata, "mpris:trackid")
+    .expect("Expected a track id")
+    .clone();
+  assert_eq!(&*no_track, "/org/mpris/MediaPlayer2/TrackList/NoTrack");
+  client
+    .call::<_, ()>(PLAYER, "SetPosition", (no_track, 20_000_000i64))
+    .unwrap();
+
+  assert_eq!(
+    player.next_event(),
+    ("positionchanged".to_string(), "20".to_string())
+  );
+}
+
+#[test]
+fn method_calls_emit_events() {
+  let (_bus, mut player, client) = support::start("Xosms Test");
+  player.eval("player.pauseButtonEnabled = true");
+  player.eval("player.nextButtonEnabled = true");
+  player.eval("player.supportedUriSchemes = ['https']");
+
+  client.call::<_, ()>(PLAYER, "PlayPause", ()).unwrap();
+  assert_eq!(
+    player.next_event(),
+    ("buttonpressed".to_string(), "\"playpause\"".to_string())
+  );
+  client.call::<_, ()>(PLAYER, "Next", ()).unwrap();
+  assert_eq!(
+    player.next_event(),
+    ("buttonpressed".to_string(), "\"next\"".to_string())
+  );
+
+  let error = client
+    .call::<_, ()>(PLAYER, "OpenUri", ("file:///music.mp3",))
+    .unwrap_err();
+  assert_eq!(
+    error.name(),
+    Some("org.freedesktop.DBus.Error.NotSupported")
+  );
+  client
+    .call::<_, ()>(PLAYER, "OpenUri", ("https://example.com/music.mp3",))
+    .unwrap();
+  assert_eq!(
+    player.next_event(),
+    (
+      "openuri".to_string(),
+      "\"https://example.com/music.mp3\"".to_string()
+    )
+  );
+}
+
+#[test]
+fn deactivate_releases_the_service_name() {
+  let (_bus, mut player, client) = support::start("Xosms Test");
+  assert!(client.has_owner());
+
+  player.eval("player.deactivate()");
+  assert!(!client.has_owner());
+  assert!(client.call::<_, ()>(PLAYER, "Play", ()).is_err());
+}
@@ -0,0 +1,289 @@
+use std::{
+  collections::VecDeque,
+  env,
+  io::{BufRead, BufReader, Write},
+  path::PathBuf,
+  process::{Child, ChildStdin, Command, Stdio},
+  sync::{
+    mpsc::{self, Receiver},
+    Arc, Mutex, Once,
+  },
+  thread,
+  time::{Duration, Instant},
+};
+
+use dbus::{
+  arg::{AppendAll, Get, ReadAll},
+  blocking::{stdintf::org_freedesktop_dbus::Properties, Connection},
+  channel::{Channel, MatchingReceiver},
+  message::{MatchRule, MessageType},
+  Message,
+};
+
+/// How long to wait for the media player or the bus before failing the test
+pub const TIMEOUT: Duration = Duration::from_secs(5);
+
+pub const SERVICE_NAME: &str = "xosmstest";
+pub const BUS_NAME: &str = "org.mpris.MediaPlayer2.xosmstest";
+pub const OBJECT_PATH: &str = "/org/mpris/MediaPlayer2";
+
+/// Private session bus which is stopped when dropped
+pub struct TestBus {
+  daemon: Child,
+  address: String,
+}
+
+impl TestBus {
+  pub fn start() -> Self {
+    let mut daemon = Command::new("dbus-daemon")
+      .args(["--session", "--nofork", "--print-address"])
+      .stdout(Stdio::piped())
+      .spawn()
+      .expect("Failed to start dbus-daemon, is it installed?");
+    let mut address = String::new();
+    BufReader::new(daemon.stdout.take().unwrap())
+      .read_line(&mut address)
+      .expect("Failed to read the address of dbus-daemon");
+
+    Self {
+      daemon,
+      address: address.trim().to_string(),
+    }
+  }
+
+  pub fn address(&self) -> &str {
+    &self.address
+  }
+}
+
+impl Drop for TestBus {
+  fn drop(&mut self) {
+    let _ = self.daemon.kill();
+    let _ = self.daemon.wait();
+  }
+}
+
+/// MediaPlayer hosted by a node process, see player.js
+pub struct TestPlayer {
+  node: Child,
+  stdin: ChildStdin,
+  replies: Receiver<Result<String, String>>,
+  events: Receiver<(String, String)>,
+}
+
+impl TestPlayer {
+  pub fn start(bus: &TestBus, identity: &str) -> Self {
+    let mut node = Command::new("node")
+      .arg(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/support/player.js"
+      ))
+      .env("XOSMS_ADDON", addon_path())
+      .env("XOSMS_SERVICE_NAME", SERVICE_NAME)
+      .env("XOSMS_IDENTITY", identity)
+      .env("DBUS_SESSION_BUS_ADDRESS", bus.address())
+      .stdin(Stdio::piped())
+      .stdout(Stdio::piped())
+      .spawn()
+      .expect("Failed to start node, is it installed?");
+    let stdin = node.stdin.take().unwrap();
+    let stdout = node.stdout.take().unwrap();
+
+    let (reply_sender, replies) = mpsc::channel();
+    let (event_sender, events) = mpsc::channel();
+    thread::spawn(move || {
+      for line in BufReader::new(stdout).lines() {
+        let Ok(line) = line else {
+          break;
+        };
+        let (kind, rest) = line.split_once(' ').unwrap_or((&line, ""));
+        match kind {
+          "ok" => {
+            let _ = reply_sender.send(Ok(rest.to_string()));
+          }
+          "error" => {
+            let _ = reply_sender.send(Err(rest.to_string()));
+          }
+          "event" => {
+            let (name, value) = rest.split_once(' ').unwrap_or((rest, "null"));
+            let _ = event_sender.send((name.to_string(), value.to_string()));
+          }
+          _ => {}
+        }
+      }
+    });
+
+    Self {
+      node,
+      stdin,
+      replies,
+      events,
+    }
+  }
+
+  /// Evaluates JS with `player` and `xosms` in scope, returning the JSON of the result
+  pub fn try_eval(&mut self, code: &str) -> Result<String, String> {
+    writeln!(self.stdin, "{}", code).expect("Failed to write to node");
+    self
+      .replies
+      .recv_timeout(TIMEOUT)
+      .unwrap_or_else(|_| panic!("Timed out evaluating {}", code))
+  }
+
+  pub fn eval(&mut self, code: &str) -> String {
+    self
+      .try_eval(code)
+      .unwrap_or_else(|error| panic!("Evaluating {} failed: {}", code, error))
+  }
+
+  /// Waits for the next event emitted by the media player, returning its name and the JSON of its value
+  pub fn next_event(&self) -> (String, String) {
+    self
+      .events
+      .recv_timeout(TIMEOUT)
+      .expect("Timed out waiting for an event")
+  }
+}
+
+impl Drop for TestPlayer {
+  fn drop(&mut self) {
+    let _ = self.node.kill();
+    let _ = self.node.wait();
+  }
+}
+
+/// MPRIS client which records the signals emitted by the media player
+pub struct MprisClient {
+  connection: Connection,
+  signals: Arc<Mutex<VecDeque<Message>>>,
+}
+
+impl MprisClient {
+  pub fn connect(bus: &TestBus) -> Self {
+    let mut channel = Channel::open_private(bus.address()).expect("Failed to connect to the bus");
+    channel.register().expect("Failed to register on the bus");
+    let connection = Connection::from(channel);
+
+    let rule = MatchRule::new()
+      .with_type(MessageType::Signal)
+      .with_path(OBJECT_PATH);
+    connection
+      .add_match_no_cb(&rule.match_str())
+      .expect("Failed to add the signal match");
+    let signals = Arc::new(Mutex::new(VecDeque::new()));
+    let received = signals.clone();
+    connection.start_receive(
+      rule,
+      Box::new(move |message, _| {
+        received.lock().unwrap().push_back(message);
+        true
+      }),
+    );
+
+    Self {
+      connection,
+      signals,
+    }
+  }
+
+  pub fn get<T: for<'a> Get<'a> + 'static>(&self, interface: &str, property: &str) -> T {
+    self
+      .connection
+      .with_proxy(BUS_NAME, OBJECT_PATH, TIMEOUT)
+      .get(interface, property)
+      .unwrap_or_else(|error| panic!("Failed to get {}.{}: {}", interface, property, error))
+  }
+
+  pub fn call<A: AppendAll, R: ReadAll>(
+    &self,
+    interface: &str,
+    method: &str,
+    args: A,
+  ) -> Result<R, dbus::Error> {
+    self
+      .connection
+      .with_proxy(BUS_NAME, OBJECT_PATH, TIMEOUT)
+      .method_call(interface, method, args)
+  }
+
+  /// Whether the media player currently owns its bus name
+  pub fn has_owner(&self) -> bool {
+    self.name_has_owner(BUS_NAME)
+  }
+
+  pub fn name_has_owner(&self, name: &str) -> bool {
+    let (has_owner,): (bool,) = self
+      .connection
+      .with_proxy("org.freedesktop.DBus", "/org/freedesktop/DBus", TIMEOUT)
+      .method_call("org.freedesktop.DBus", "NameHasOwner", (name,))
+      .expect("Failed to ask the bus for the name owner");
+    has_owner
+  }
+
+  /// Unique name of the connection owning a bus name
+  pub fn name_owner(&self, name: &str) -> String {
+    let (owner,): (String,) = self
+      .connection
+      .with_proxy("org.freedesktop.DBus", "/org/freedesktop/DBus", TIMEOUT)
+      .method_call("org.freedesktop.DBus", "GetNameOwner", (name,))
+      .expect("Failed to ask the bus for the name owner");
+    owner
+  }
+
+  /// Waits for the next signal emitted on the media player object
+  pub fn next_signal(&self) -> Message {
+    let deadline = Instant::now() + TIMEOUT;
+    loop {
+      if let Some(message) = self.signals.lock().unwrap().pop_front() {
+        return message;
+      }
+      let remaining = deadline.saturating_duration_since(Instant::now());
+      assert!(!remaining.is_zero(), "Timed out waiting for a signal");
+      self
+        .connection
+        .process(remaining)
+        .expect("Failed to read from the bus");
+    }
+  }
+}
+
+/// Starts a bus with an activated media player and a client connected to it
+pub fn start(identity: &str) -> (TestBus, TestPlayer, MprisClient) {
+  let bus = TestBus::start();
+  let mut player = TestPlayer::start(&bus, identity);
+  player.eval("player.activate()");
+  let client = MprisClient::connect(&bus);
+  (bus, player, client)
+}
+
+/// Builds the addon, which cargo does not do for integration tests as it is a cdylib only
+fn addon_path() -> PathBuf {
+  static BUILD: Once = Once::new();
+  BUILD.call_once(|| {
+    let mut cargo = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()));
+    cargo.args(["build", "--lib"]);
+    // Build with the profile of the tests so the addon ends up next to them
+    if !cfg!(debug_assertions) {
+      cargo.arg("--release");
+    }
+    let output = cargo
+      .current_dir(env!("CARGO_MANIFEST_DIR"))
+      .output()
+      .expect("Failed to run cargo build");
+    assert!(
+      output.status.success(),
+      "Failed to build the addon\n{}",
+      String::from_utf8_lossy(&output.stderr)
+    );
+  });
+
+  // Test executables are placed in the deps directory next to the addon
+  let test_executable = env::current_exe().expect("Failed to locate the test executable");
+  let addon = test_executable
+    .parent()
+    .and_then(|deps| deps.parent())
+    .unwrap()
+    .join("libxosms.so");
+  assert!(addon.exists(), "Failed to locate {}", addon.display());
+  addon
+}
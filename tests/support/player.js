@@ -0,0 +1,40 @@
+// Hosts a MediaPlayer for the Rust integration tests
+//
+// Every line read from stdin is evaluated with `player` in scope and answered with `ok <json>` or `error <message>`.
+// Events are reported as `event <name> <json>` in the order their listeners are called.
+const readline = require("readline");
+
+const addon = { exports: {} };
+process.dlopen(addon, process.env.XOSMS_ADDON);
+const xosms = addon.exports;
+
+const player = new xosms.MediaPlayer(process.env.XOSMS_SERVICE_NAME, process.env.XOSMS_IDENTITY);
+
+const events = [
+  "buttonpressed",
+  "positionchanged",
+  "positionseeked",
+  "trackgoto",
+  "repeatmodechanged",
+  "shufflechanged",
+  "volumechanged",
+  "ratechanged",
+  "raise",
+  "quit",
+  "openuri",
+  "fullscreenchanged",
+];
+for (const event of events) {
+  player.on(event, (error, value) => {
+    process.stdout.write(`event ${event} ${JSON.stringify(error ? null : value ?? null)}\n`);
+  });
+}
+
+readline.createInterface({ input: process.stdin }).on("line", (line) => {
+  try {
+    const result = eval(line);
+    process.stdout.write(`ok ${JSON.stringify(result ?? null)}\n`);
+  } catch (error) {
+    process.stdout.write(`error ${String(error.message).replace(/\n/g, " ")}\n`);
+  }
+}).on("close", () => process.exit(0));